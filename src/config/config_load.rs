@@ -19,6 +19,15 @@ pub struct Config {
     pub rendering_main: RenderMainConfig,
     pub main_window: MainWindowConfig,
     pub input_window: InputWindowConfig,
+    pub keyboard: KeyboardConfig,
+    pub text: TextConfig,
+    pub subtitle: SubtitleConfig,
+    pub hanja: HanjaConfig,
+    pub prediction: PredictionConfig,
+    pub autopilot: AutopilotConfig,
+    pub improviser: ImproviserConfig,
+    pub music: MusicConfig,
+    pub audio: AudioConfig,
 }
 
 impl Config {
@@ -2,7 +2,12 @@
 //
 // Config types for the app
 
+use crate::music::{Articulations, Jangdan, Registers, Scale};
+use crate::services::{
+    keyboard_layout::KeyboardLayout, normalization::NormalForm, romanization::RomanizationSystem,
+};
 use serde::Deserialize;
+use std::collections::HashMap;
 
 #[derive(Debug, Deserialize)]
 pub struct MainWindowConfig {
@@ -33,6 +38,8 @@ pub struct FrameRecorderConfig {
 #[derive(Debug, Deserialize)]
 pub struct SpeedConfig {
     pub bpm: u32,
+    #[serde(default)] // 중모리
+    pub jangdan: Jangdan,
 }
 
 #[derive(Debug, Deserialize)]
@@ -43,4 +50,80 @@ pub struct PathConfig {
 #[derive(Debug, Deserialize)]
 pub struct OscConfig {
     pub rx_port: u16,
+    #[serde(default)] // none: events aren't sent anywhere
+    pub targets: Vec<OscTarget>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct OscTarget {
+    pub host: String,
+    pub port: u16,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct KeyboardConfig {
+    pub layout: KeyboardLayout,
+    pub hangeul_on_start: bool,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct TextConfig {
+    pub export_form: NormalForm,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SubtitleConfig {
+    pub enabled: bool,
+    pub system: RomanizationSystem,
+    pub font_size: u32,
+    pub color: [f32; 4],
+    pub x: f32,
+    pub y: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct HanjaConfig {
+    pub dictionary: String,
+    pub fallback_font: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PredictionConfig {
+    pub corpus: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AutopilotConfig {
+    pub file: Option<String>,
+    pub wpm: f32,
+    pub jitter: f32,
+    pub punctuation_pause: f32,
+    pub line_pause: f32,
+    pub typo_rate: f32,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ImproviserConfig {
+    pub order: usize,
+    pub temperature: f32,
+    pub seed: u64,
+    pub max_length: usize,
+    pub wpm: f32,
+    pub corpus_files: Vec<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct MusicConfig {
+    pub scale: Scale,
+    pub tonic: u8,
+    pub choseong_degrees: HashMap<String, i32>,
+    pub registers: Registers,
+    pub articulation: Articulations,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct AudioConfig {
+    pub live: bool,
+    pub sample_rate: u32,
+    pub volume: f32,
 }
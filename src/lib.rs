@@ -1,5 +1,6 @@
 // src/lib.rs
 pub mod config;
 pub mod effects;
+pub mod music;
 pub mod services;
 pub mod views;
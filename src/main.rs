@@ -3,29 +3,102 @@
 use egui::{FontData, FontDefinitions, FontFamily};
 use nannou::{
     prelude::*,
+    rand::{rngs::StdRng, SeedableRng},
     text::*,
-    winit::event::{ElementState, KeyboardInput, VirtualKeyCode, WindowEvent},
+    winit::event::{ElementState, KeyboardInput, ModifiersState, VirtualKeyCode, WindowEvent},
 };
+use nannou_audio as audio;
 use nannou_egui::{egui, Egui};
 use nnpipe::*;
-use std::{fs, time::Instant};
-
-use autohmjeum::{config::Config, views::BackgroundManager};
+use std::{fs, path::PathBuf, time::Instant};
+
+use autohmjeum::{
+    config::{AudioConfig, Config, SubtitleConfig},
+    music::{
+        jamo_sounds, render::save_wav, JangdanClock, Note, Quantizer, Recording, Sonifier, Synth,
+        Tick,
+    },
+    services::{
+        autotypist::{AutoTypist, Keystroke, TypingStyle},
+        composer_event::ComposerEvent,
+        hangeul_composer::HangeulComposer,
+        hanja::{HanjaDictionary, HanjaPicker},
+        improviser::NgramModel,
+        keyboard_layout::KeyMapper,
+        normalization::{to_normal_form, NormalForm},
+        osc_receiver::{OscCommand, OscReceiver},
+        osc_sender::OscSender,
+        predictor::Predictor,
+        pronunciation::{pronounce_tail, Pronunciation},
+        romanization::romanize,
+        sijo::{check_line, poem_lines},
+        syllable_analysis::{analyze, SyllableFeatures},
+    },
+    views::{BackgroundManager, SyllableEchoes},
+};
 
 struct Model {
     background: BackgroundManager,
+    echoes: SyllableEchoes, // committed syllables drifting behind the text
     text_layout: Layout,
 
     // input
     input_string: String,
     input_history: Vec<String>,
+    sijo_mode: bool,
+    sijo_start: usize, // first history line of the sijo mode session
 
     // for hangeul
-    input_committed: String,
-    input_composing: Vec<char>,
+    composer: HangeulComposer,
+    keymap: KeyMapper,
+    hanja: HanjaDictionary,
+    hanja_picker: Option<HanjaPicker>, // open candidate list
+    predictor: Predictor,
+    events: Vec<ComposerEvent>, // composer events of the current frame
+    syllables: Vec<SyllableFeatures>, // syllables committed this frame, for visuals
+    said: Pronunciation,        // how they sound, for the music
+    sonifier: Sonifier,
+    note_queue: Quantizer<Note>, // notes waiting for the next stroke
+    notes: Vec<Note>,            // notes landing on this frame's strokes
+
+    // rhythm
+    jangdan: JangdanClock,
+    ticks: Vec<Tick>,        // 소박 passed this frame
+    export_form: NormalForm, // normal form of submitted lines
+
+    // Live sound
+    audio_stream: Option<audio::Stream<Synth>>, // None without an output device
+    volume: f32,
+    muted: bool,
+
+    // MIDI and WAV export
+    recording: Recording, // every note and stroke played, for the DAW
+    output_dir: PathBuf,
+    audio_config: AudioConfig,
+
+    // autopilot
+    autopilot_text: Option<String>,
+    typing_style: TypingStyle,
+    autotypist: Option<AutoTypist>, // running performance
+
+    // improvisation
+    ngram: NgramModel,
+    improvising: bool,
+    improv_temperature: f32,
+    improv_seed: u64,
+    improv_max_length: usize,
+    improv_style: TypingStyle,
+    improv_rng: StdRng, // seeded, so a seed replays the same lines
 
     main_font: Font,
+    fallback_font: Option<Font>, // for glyphs the main font lacks (Hanja)
+    subtitle: SubtitleConfig,
     input_focus_next_frame: bool,
+    modifiers: ModifiersState, // held modifier keys, for Shift+Enter
+
+    // Remote control
+    osc_receiver: Option<OscReceiver>,
+    osc_sender: OscSender,
 
     // Random
     rng: nannou::rand::rngs::ThreadRng,
@@ -67,6 +140,99 @@ fn model(app: &App) -> Model {
     let main_font = Font::from_bytes(font_bytes)
         .unwrap_or_else(|_| panic!("Failed to load font at {:?}", font_path));
 
+    // Optional CJK fallback font, shared with egui below. Like the Hanja
+    // dictionary, a missing or broken file is reported and left out.
+    let fallback = config.hanja.fallback_font.as_ref().and_then(|name| {
+        let path = assets.join(name);
+        let bytes = fs::read(&path)
+            .map_err(|e| println!("No fallback font at {:?}: {}", path, e))
+            .ok()?;
+        match Font::from_bytes(bytes.clone()) {
+            Ok(font) => Some((font, bytes)),
+            Err(_) => {
+                println!("Could not load fallback font at {:?}", path);
+                None
+            }
+        }
+    });
+    let fallback_font_bytes = fallback.as_ref().map(|(_, bytes)| bytes.clone());
+    let fallback_font = fallback.map(|(font, _)| font);
+
+    // --- Word completion, seeded from the optional corpus ---
+    let mut predictor = Predictor::new();
+    if let Some(corpus) = &config.prediction.corpus {
+        let corpus_path = assets.join(corpus);
+        if let Err(e) = predictor.load_corpus(&corpus_path) {
+            println!("Could not read corpus at {:?}: {}", corpus_path, e);
+        }
+    }
+
+    // --- Autopilot text, performed on F5 ---
+    let autopilot_text = config.autopilot.file.as_ref().and_then(|name| {
+        let path = assets.join(name);
+        fs::read_to_string(&path)
+            .map_err(|e| println!("Could not read autopilot text at {:?}: {}", path, e))
+            .ok()
+    });
+    let typing_style = TypingStyle {
+        wpm: config.autopilot.wpm,
+        jitter: config.autopilot.jitter,
+        punctuation_pause: config.autopilot.punctuation_pause,
+        line_pause: config.autopilot.line_pause,
+        typo_rate: config.autopilot.typo_rate,
+    };
+
+    // --- Improviser, trained on submitted lines and the corpus files ---
+    let mut ngram = NgramModel::new(config.improviser.order);
+    for corpus in &config.improviser.corpus_files {
+        let corpus_path = assets.join(corpus);
+        if let Err(e) = ngram.load_corpus(&corpus_path) {
+            println!("Could not read corpus at {:?}: {}", corpus_path, e);
+        }
+    }
+    let improv_style = TypingStyle {
+        wpm: config.improviser.wpm,
+        ..typing_style
+    };
+
+    // --- Syllables to notes ---
+    let music = &config.music;
+    let sonifier = Sonifier::new(
+        music.scale,
+        music.tonic,
+        &music.choseong_degrees,
+        music.registers,
+        music.articulation,
+    );
+
+    // --- Hanja dictionary; conversion is simply off without one ---
+    let hanja_path = assets.join(&config.hanja.dictionary);
+    let hanja = HanjaDictionary::load(&hanja_path).unwrap_or_else(|e| {
+        println!("No Hanja dictionary at {:?}: {}", hanja_path, e);
+        HanjaDictionary::default()
+    });
+
+    // --- OSC remote control; the piece still runs if the port is taken ---
+    let osc_receiver = OscReceiver::spawn(config.osc.rx_port)
+        .map_err(|e| {
+            println!(
+                "OSC: could not listen on port {}: {}",
+                config.osc.rx_port, e
+            )
+        })
+        .ok();
+
+    let osc_targets: Vec<String> = config
+        .osc
+        .targets
+        .iter()
+        .map(|target| format!("{}:{}", target.host, target.port))
+        .collect();
+    let osc_sender = OscSender::new(&osc_targets, config.text.export_form);
+
+    // --- Live sound; the piece still runs without an output device ---
+    let audio_stream = open_audio(&config.audio);
+
     // Create main output window
     let main_window_id = app
         .new_window()
@@ -153,6 +319,19 @@ fn model(app: &App) -> Model {
         .entry(FontFamily::Monospace)
         .or_default()
         .insert(0, font_name.clone());
+    if let Some(bytes) = fallback_font_bytes {
+        let fallback_name = "Fallback".to_owned();
+        fonts
+            .font_data
+            .insert(fallback_name.clone(), FontData::from_owned(bytes));
+        for family in [FontFamily::Proportional, FontFamily::Monospace] {
+            fonts
+                .families
+                .entry(family)
+                .or_default()
+                .insert(1, fallback_name.clone());
+        }
+    }
     egui.ctx().set_fonts(fonts);
 
     let text_layout_builder = nannou::text::layout::Builder::default();
@@ -164,15 +343,55 @@ fn model(app: &App) -> Model {
 
     Model {
         background: BackgroundManager::new(rgb(0.05, 0.03, 0.0)),
+        echoes: SyllableEchoes::new(),
         text_layout,
 
         input_string: String::new(),
         input_history: Vec::new(),
-
-        input_committed: String::new(),
-        input_composing: Vec::new(),
+        sijo_mode: false,
+        sijo_start: 0,
+
+        composer: HangeulComposer::new(),
+        keymap: KeyMapper::new(config.keyboard.layout, config.keyboard.hangeul_on_start),
+        hanja,
+        hanja_picker: None,
+        predictor,
+        events: Vec::new(),
+        syllables: Vec::new(),
+        said: Pronunciation::default(),
+        sonifier,
+        note_queue: Quantizer::new(),
+        notes: Vec::new(),
+
+        jangdan: JangdanClock::new(config.speed.jangdan, config.speed.bpm as f32),
+        ticks: Vec::new(),
+        export_form: config.text.export_form,
+
+        audio_stream,
+        volume: config.audio.volume,
+        muted: false,
+
+        recording: Recording::new(config.speed.bpm as f32),
+        output_dir: config.resolve_output_dir(),
+        audio_config: config.audio,
+
+        autopilot_text,
+        typing_style,
+        autotypist: None,
+
+        ngram,
+        improvising: false,
+        improv_temperature: config.improviser.temperature,
+        improv_seed: config.improviser.seed,
+        improv_max_length: config.improviser.max_length,
+        improv_style,
+        improv_rng: StdRng::seed_from_u64(config.improviser.seed),
 
         input_focus_next_frame: true,
+        modifiers: ModifiersState::empty(),
+
+        osc_receiver,
+        osc_sender,
 
         rng: nannou::rand::thread_rng(),
 
@@ -182,6 +401,8 @@ fn model(app: &App) -> Model {
         texture_reshaper_main,
 
         main_font,
+        fallback_font,
+        subtitle: config.subtitle,
         egui,
 
         post_processing,
@@ -198,7 +419,11 @@ fn model(app: &App) -> Model {
 }
 
 fn main() {
-    nannou::app(model).update(update).run();
+    nannou::app(model).update(update).exit(exit).run();
+}
+
+fn exit(_app: &App, model: Model) {
+    export_midi(&model);
 }
 
 fn update(app: &App, model: &mut Model, update: Update) {
@@ -214,10 +439,21 @@ fn update(app: &App, model: &mut Model, update: Update) {
 
     // Grab the input from keyboard
     update_input(app, model, update);
+    handle_osc(app, model);
+    run_autopilot(model, dt);
+    handle_composer_events(model);
+    run_jangdan(model, dt);
+    pulse_background(app, model);
 
     // Handle the background
     model.background.draw(&model.draw, app.time);
 
+    // Every syllable committed this frame leaves an echo shaped by its features
+    for syllable in &model.syllables {
+        model.echoes.push(syllable, app.time);
+    }
+    model.echoes.draw(&model.draw, &model.main_font, app.time);
+
     // Update & draw
     draw_output(model);
 
@@ -234,34 +470,171 @@ fn view_main(_app: &App, model: &Model, frame: Frame) {
 }
 
 fn draw_output(model: &Model) {
-    let (clusters, _) = cluster_jamo_with_spans(&model.input_composing);
+    let display = model.composer.display();
 
-    // 2) if the *whole* thing makes one syllable, show that; else show clusters
-    let tail: Vec<char> = if let Some(one) = collapse_to_syllable(&clusters) {
-        vec![one]
+    if model.sijo_mode {
+        draw_sijo(model, &display);
     } else {
-        clusters
-    };
+        model
+            .draw
+            .text(&display)
+            .layout(&model.text_layout)
+            .width(1000.0)
+            .font(font_for(model, &display))
+            .x_y(0.0, 0.0)
+            .color(rgba(0.71, 0.71, 1.0, 1.0))
+            // swells a little on each stroke
+            .font_size((50.0 * (1.0 + 0.06 * model.jangdan.pulse())) as u32);
+    }
 
-    // 3) stitch together
-    let mut display = model.input_committed.clone();
-    display.extend(tail.iter());
+    // Romanized subtitle, follows every keystroke
+    let subtitle = &model.subtitle;
+    if subtitle.enabled {
+        let [r, g, b, a] = subtitle.color;
+        // the output is centred, so each extra line grows it half a line downwards
+        let extra_lines = display.lines().count().saturating_sub(1) as f32;
+        let y = subtitle.y - extra_lines * 30.0;
+        model
+            .draw
+            .text(&romanize(&display, subtitle.system))
+            .layout(&model.text_layout)
+            .width(1000.0)
+            .font(model.main_font.clone())
+            .x_y(subtitle.x, y)
+            .color(rgba(r, g, b, a))
+            .font_size(subtitle.font_size);
+    }
+    draw_jangdan(model);
 
-    model
-        .draw
-        .text(&display)
-        .layout(&model.text_layout)
-        .width(1000.0)
-        .font(model.main_font.clone())
-        .x_y(0.0, 0.0)
-        .color(rgba(0.71, 0.71, 1.0, 1.0))
-        .font_size(50);
     // Handle FPS and origin display
     if model.verbose {
         draw_fps(model);
     }
 }
 
+/// The jangdan along the bottom edge: a dot per 박, the current one lit by
+/// its stroke, and a line for how far through the cycle the clock is.
+fn draw_jangdan(model: &Model) {
+    let jangdan = &model.jangdan;
+    let (beats, _) = jangdan.jangdan().meter();
+    // in from the bottom-left corner of the render texture
+    let [texture_width, texture_height] = model.texture_main.size();
+    let left = -(texture_width as f32) / 2.0 + 100.0;
+    let y = -(texture_height as f32) / 2.0 + 50.0;
+    let spacing = 24.0;
+    let pulse = jangdan.pulse();
+
+    for beat in 0..beats {
+        let (radius, alpha) = if beat == jangdan.beat() {
+            (5.0 + 4.0 * pulse, 0.4 + 0.6 * pulse)
+        } else {
+            (4.0, 0.2)
+        };
+        model
+            .draw
+            .ellipse()
+            .x_y(left + beat as f32 * spacing, y)
+            .radius(radius)
+            .color(rgba(0.71, 0.71, 1.0, alpha));
+    }
+
+    let width = (beats - 1) as f32 * spacing;
+    model
+        .draw
+        .line()
+        .points(
+            pt2(left, y - 16.0),
+            pt2(left + width * jangdan.phase(), y - 16.0),
+        )
+        .color(rgba(0.71, 0.71, 1.0, 0.4))
+        .stroke_weight(2.0);
+
+    model
+        .draw
+        .text(jangdan.jangdan().name())
+        .left_justify()
+        .width(200.0)
+        .x_y(left + 100.0, y + 28.0)
+        .font(model.main_font.clone())
+        .color(rgba(0.71, 0.71, 1.0, 0.5))
+        .font_size(18);
+}
+
+/// Sijo mode: the poem in progress in its three-line shape, with groups
+/// that break the meter highlighted.
+fn draw_sijo(model: &Model, display: &str) {
+    let font_size = 40;
+    let line_height = 70.0;
+    let left = -450.0;
+    let space = font_size as f32 * 0.5;
+
+    let submitted = &model.input_history[model.sijo_start..];
+    let lines = poem_lines(submitted, display);
+    let typing_line = if display.is_empty() {
+        None
+    } else {
+        Some(lines.len() - 1)
+    };
+
+    for (i, line) in lines.iter().enumerate() {
+        let y = line_height - i as f32 * line_height;
+        let groups = check_line(i, line);
+        let mut x = left;
+
+        for (j, group) in groups.iter().enumerate() {
+            // the group under the cursor only breaks the meter once it's too long
+            let in_progress =
+                typing_line == Some(i) && j == groups.len() - 1 && !line.ends_with(' ');
+            let too_short = group.expected.is_some_and(|(min, _)| group.syllables < min);
+            let broken = !group.fits && !(in_progress && too_short);
+
+            let color = if broken {
+                rgba(1.0, 0.35, 0.3, 1.0)
+            } else {
+                rgba(0.71, 0.71, 1.0, 1.0)
+            };
+
+            // text boxes are centred; pad so the group never wraps
+            let width = estimate_text_width(&group.text, font_size);
+            let box_width = width + font_size as f32;
+            model
+                .draw
+                .text(&group.text)
+                .layout(&model.text_layout)
+                .width(box_width)
+                .font(font_for(model, &group.text))
+                .x_y(x + box_width / 2.0, y)
+                .color(color)
+                .font_size(font_size);
+            x += width + space;
+        }
+    }
+}
+
+/// The main font, or the fallback when the main font is missing glyphs
+/// for `text` (Hanja outside Gulim's set, for instance).
+fn font_for(model: &Model, text: &str) -> Font {
+    match &model.fallback_font {
+        Some(fallback)
+            if text
+                .chars()
+                .any(|c| !c.is_whitespace() && model.main_font.glyph(c).id().0 == 0) =>
+        {
+            fallback.clone()
+        }
+        _ => model.main_font.clone(),
+    }
+}
+
+/// Rough advance of a run of text: Hangeul and other wide glyphs take a
+/// full em, everything else about half.
+fn estimate_text_width(text: &str, font_size: u32) -> f32 {
+    text.chars()
+        .map(|c| if c.is_ascii() { 0.55 } else { 1.0 })
+        .sum::<f32>()
+        * font_size as f32
+}
+
 // ******************************* Rendering and Capture *****************************
 
 fn render_and_post(app: &App, model: &mut Model) {
@@ -353,6 +726,20 @@ fn update_input(_app: &App, model: &mut Model, update: Update) {
 
     let ctx = egui.begin_frame();
 
+    // Pasted text may be NFC, NFD or loose jamo; the composer sorts it out
+    let pasted: Vec<String> = ctx.input(|i| {
+        i.events
+            .iter()
+            .filter_map(|event| match event {
+                egui::Event::Paste(text) => Some(text.clone()),
+                _ => None,
+            })
+            .collect()
+    });
+    for text in pasted {
+        model.composer.push_str(&text);
+    }
+
     let text_edit_id = egui::Id::new("input_field");
     let output_panel_id = egui::Id::new("output_panel");
 
@@ -366,6 +753,14 @@ fn update_input(_app: &App, model: &mut Model, update: Update) {
         ..Default::default()                    // Use defaults for unspecified fields
     };
 
+    // Hanja candidate clicked in the list below
+    let mut picked_hanja = None;
+    // Improvisation controls, applied after the panel
+    let mut improvising = model.improvising;
+    let mut reseed = false;
+    // Sound on/off and volume, sent to the audio thread after the panel
+    let mut volume_changed = false;
+
     // Input Text field
     egui::TopBottomPanel::bottom("input_panel")
         .frame(bottom_frame) // Apply the custom frame style
@@ -384,6 +779,109 @@ fn update_input(_app: &App, model: &mut Model, update: Update) {
                     model.input_focus_next_frame = true;
                 }
 
+                // Improvisation: on/off, temperature and seed
+                egui::Frame::none()
+                    .inner_margin(egui::Margin {
+                        left: 10.0,
+                        right: 10.0,
+                        top: 0.0,
+                        bottom: 6.0,
+                    })
+                    .show(ui, |ui| {
+                        ui.horizontal(|ui| {
+                            ui.checkbox(
+                                &mut improvising,
+                                egui::RichText::new("즉흥 F6")
+                                    .color(egui::Color32::WHITE)
+                                    .size(14.0),
+                            );
+                            ui.add(
+                                egui::Slider::new(&mut model.improv_temperature, 0.1..=2.0)
+                                    .text("temperature"),
+                            );
+                            reseed = ui
+                                .add(egui::DragValue::new(&mut model.improv_seed).prefix("seed "))
+                                .changed();
+                        });
+                    });
+
+                // Live sound: mute and master volume
+                if model.audio_stream.is_some() {
+                    egui::Frame::none()
+                        .inner_margin(egui::Margin {
+                            left: 10.0,
+                            right: 10.0,
+                            top: 0.0,
+                            bottom: 6.0,
+                        })
+                        .show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                let mut sound_on = !model.muted;
+                                volume_changed |= ui
+                                    .checkbox(
+                                        &mut sound_on,
+                                        egui::RichText::new("소리")
+                                            .color(egui::Color32::WHITE)
+                                            .size(14.0),
+                                    )
+                                    .changed();
+                                model.muted = !sound_on;
+                                volume_changed |= ui
+                                    .add(
+                                        egui::Slider::new(&mut model.volume, 0.0..=1.0)
+                                            .text("volume"),
+                                    )
+                                    .changed();
+                            });
+                        });
+                }
+
+                // Completion offered for the current word
+                if let Some(word) = model.predictor.complete(&model.composer.current_word()) {
+                    egui::Frame::none()
+                        .inner_margin(egui::Margin {
+                            left: 10.0,
+                            right: 10.0,
+                            top: 0.0,
+                            bottom: 6.0,
+                        })
+                        .show(ui, |ui| {
+                            ui.label(
+                                egui::RichText::new(format!("Tab → {}", word))
+                                    .color(egui::Color32::GRAY)
+                                    .size(14.0),
+                            );
+                        });
+                }
+
+                // Hanja candidates for the word before the cursor
+                if let Some(picker) = &model.hanja_picker {
+                    egui::Frame::none()
+                        .inner_margin(egui::Margin {
+                            left: 10.0,
+                            right: 10.0,
+                            top: 0.0,
+                            bottom: 6.0,
+                        })
+                        .show(ui, |ui| {
+                            ui.horizontal_wrapped(|ui| {
+                                for (i, candidate) in picker.candidates.iter().enumerate() {
+                                    let label = egui::RichText::new(format!(
+                                        "{} {} {}",
+                                        i + 1,
+                                        candidate.hanja,
+                                        candidate.meaning
+                                    ))
+                                    .color(egui::Color32::WHITE)
+                                    .size(14.0);
+                                    if ui.selectable_label(i == picker.selected, label).clicked() {
+                                        picked_hanja = Some(i);
+                                    }
+                                }
+                            });
+                        });
+                }
+
                 egui::Frame::none()
                     .inner_margin(egui::Margin {
                         left: 10.0,
@@ -395,36 +893,41 @@ fn update_input(_app: &App, model: &mut Model, update: Update) {
                         ui.horizontal(|ui| {
                             // Style the prompt label
                             ui.label(
-                                egui::RichText::new("> ")
+                                egui::RichText::new(model.keymap.prompt())
                                     .color(egui::Color32::WHITE) // Prompt color
                                     //.monospace() // Use monospace font
                                     .size(14.0), // Font size
                             );
 
                             // build the display field
-                            let (clusters, _) = cluster_jamo_with_spans(&model.input_composing);
-
-                            // 2) if the *whole* thing makes one syllable, show that; else show clusters
-                            let tail: Vec<char> = if let Some(one) = collapse_to_syllable(&clusters)
-                            {
-                                vec![one]
-                            } else {
-                                clusters
-                            };
-
-                            // 3) stitch together
-                            let mut display = model.input_committed.clone();
-                            display.extend(tail.iter());
+                            let mut display = model.composer.display();
 
                             // Style the text edit field
-                            let response = ui.add(
-                                egui::TextEdit::singleline(&mut display) // Use model.input_string or input_text
-                                    .id_source(text_edit_id)
-                                    .desired_width(f32::INFINITY) // Fill available width
-                                    //.font(egui::TextStyle::Monospace) // Use monospace font
-                                    .frame(false) // Remove the default TextEdit frame for flatter look
-                                    .text_color(egui::Color32::WHITE), // Text color
-                            );
+                            // Multiline so Shift+Enter breaks show; Enter itself never reaches it
+                            let output = egui::TextEdit::multiline(&mut display) // Use model.input_string or input_text
+                                .id_source(text_edit_id)
+                                .desired_rows(1)
+                                .desired_width(f32::INFINITY) // Fill available width
+                                //.font(egui::TextStyle::Monospace) // Use monospace font
+                                .frame(false) // Remove the default TextEdit frame for flatter look
+                                .text_color(egui::Color32::WHITE) // Text color
+                                .lock_focus(true) // Tab completes instead of moving focus
+                                .show(ui);
+                            let response = output.response;
+
+                            // The composer owns the cursor: a click moves it there,
+                            // otherwise egui's cursor is put back where the composer has it
+                            if response.clicked() {
+                                if let Some(range) = output.cursor_range {
+                                    model.composer.set_cursor(range.primary.ccursor.index);
+                                }
+                            }
+                            let mut state = output.state;
+                            let cursor = egui::text::CCursor::new(model.composer.cursor());
+                            state.set_ccursor_range(Some(egui::text_edit::CCursorRange::one(
+                                cursor,
+                            )));
+                            state.store(ui.ctx(), response.id);
 
                             // Auto-focus logic
                             if model.input_focus_next_frame {
@@ -432,16 +935,11 @@ fn update_input(_app: &App, model: &mut Model, update: Update) {
                                 model.input_focus_next_frame = false;
                             }
 
-                            // Handle Enter key press
+                            // Enter is submitted through the composer in raw_window_event;
+                            // here we only keep the field focused.
                             if response.lost_focus()
                                 && ui.input(|i| i.key_pressed(egui::Key::Enter))
                             {
-                                println!("Input submitted: {}", display); // Use model.input_string or input_text
-                                                                          // ** Trigger your actions (OSC, etc.) here **
-                                model.input_history.push(display.clone());
-                                model.input_committed.clear();
-                                model.input_composing.clear();
-
                                 response.request_focus();
                             }
                         }); // end horizontal
@@ -449,6 +947,19 @@ fn update_input(_app: &App, model: &mut Model, update: Update) {
             }); // end vertical
         }); // end centered_and_justified
 
+    if let Some(index) = picked_hanja {
+        pick_hanja(&mut model.hanja_picker, &mut model.composer, index);
+    }
+    if improvising != model.improvising {
+        set_improvising(model, improvising);
+    }
+    if reseed {
+        model.improv_rng = StdRng::seed_from_u64(model.improv_seed);
+    }
+    if volume_changed {
+        send_volume(model);
+    }
+
     let history_frame = egui::Frame {
         fill: egui::Color32::from_rgb(0, 0, 0),
         inner_margin: (egui::Margin {
@@ -508,22 +1019,87 @@ fn raw_window_event(_app: &App, model: &mut Model, event: &nannou::winit::event:
         ..
     } = event
     {
+        // An open Hanja list takes the arrows, Enter and Backspace
+        if model.hanja_picker.is_some() && handle_hanja_key(model, *key) {
+            return;
+        }
+
         match key {
+            VirtualKeyCode::Return if model.modifiers.shift() => {
+                // Shift+Enter breaks the line; the stanza is submitted as one
+                model.composer.newline();
+            }
             VirtualKeyCode::Return => {
                 // Enter key (Return on macOS/Windows)
                 handle_enter_commit(model);
             }
             VirtualKeyCode::Back => {
-                println!(
-                    "before: {}, {:?}",
-                    model.input_committed, model.input_composing
-                );
                 // Backspace key (also Delete on macOS input for back deletion)
                 handle_backspace(model);
-                println!(
-                    "after: {}, {:?}",
-                    model.input_committed, model.input_composing
-                );
+            }
+            VirtualKeyCode::Tab => {
+                complete_word(model);
+            }
+            VirtualKeyCode::Delete => {
+                model.composer.delete();
+            }
+            VirtualKeyCode::Left => {
+                model.composer.move_left();
+            }
+            VirtualKeyCode::Right => {
+                model.composer.move_right();
+            }
+            VirtualKeyCode::Home => {
+                model.composer.move_home();
+            }
+            VirtualKeyCode::End => {
+                model.composer.move_end();
+            }
+            VirtualKeyCode::RAlt | VirtualKeyCode::Kana => {
+                // Right Alt emulates the 한/영 key (Kana is VK_HANGUL on Windows)
+                model.keymap.toggle();
+            }
+            VirtualKeyCode::F2 => {
+                // Cycle 두벌식 → 세벌식 390 → 세벌식 최종 → 로마자
+                model.keymap.cycle_layout();
+            }
+            VirtualKeyCode::F3 => {
+                // 옛한글: ㆍ ㅿ ㆁ ㆆ on Shift+K/A/D/G, clusters like ㅴ by typing ㅂㅅㄱ
+                let on = model.keymap.toggle_old_hangeul();
+                model.composer.set_old_hangeul(on);
+            }
+            VirtualKeyCode::F9 | VirtualKeyCode::Kanji => {
+                // Kanji is VK_HANJA on Windows
+                let word = model.composer.word_before_cursor();
+                model.hanja_picker = HanjaPicker::open(&model.hanja, &word);
+            }
+            VirtualKeyCode::F4 => {
+                // 시조 mode starts a fresh poem from the next submitted line
+                model.sijo_mode = !model.sijo_mode;
+                model.sijo_start = model.input_history.len();
+            }
+            VirtualKeyCode::F5 => {
+                toggle_autopilot(model);
+            }
+            VirtualKeyCode::F6 => {
+                set_improvising(model, !model.improvising);
+            }
+            VirtualKeyCode::F8 => {
+                let jangdan = model.jangdan.jangdan().next();
+                model.jangdan.set_jangdan(jangdan);
+                println!("Jangdan: {}", jangdan.name());
+            }
+            VirtualKeyCode::F7 => {
+                // 평조 → 계면조 → 메나리토리 → 경토리
+                model.sonifier.scale = model.sonifier.scale.next();
+                println!("Scale: {}", model.sonifier.scale.name());
+            }
+            VirtualKeyCode::F10 => {
+                // the session so far as a MIDI file; it is saved again on exit
+                export_midi(model);
+            }
+            VirtualKeyCode::F11 => {
+                render_wav(model);
             }
             _ => {}
         }
@@ -531,318 +1107,425 @@ fn raw_window_event(_app: &App, model: &mut Model, event: &nannou::winit::event:
 
     // Look for pure character input
     if let nannou::winit::event::WindowEvent::ReceivedCharacter(ch) = event {
-        println!("Character: {}", ch);
-        if is_punctuation(ch) {
-            handle_punctuation_commit(model, *ch);
+        // With the Hanja list open, number keys pick and anything else closes it
+        if let Some(picker) = &model.hanja_picker {
+            if let Some(index) = picker.numbered(*ch) {
+                pick_hanja(&mut model.hanja_picker, &mut model.composer, index);
+                return;
+            }
+            model.hanja_picker = None;
+        }
+
+        // Latin keystrokes become jamo here when the software layout is on
+        let ch = model.keymap.map_char(*ch);
+        if model.verbose {
+            println!("Character: {}", ch);
+        }
+        if model.keymap.is_romaja() && model.composer.push_romaja(ch) {
             return;
         }
+        handle_typed_char(model, ch);
+    }
 
-        let code = *ch as u32;
-        let is_jamo = hangeul::is_jamo(code) || hangeul::is_compat_jamo(code);
-        let is_vowel = hangeul::is_moeum(code);
-
-        if is_vowel {
-            if model.input_composing.is_empty()
-                && hangeul::ends_with_jongseong(&model.input_committed).unwrap_or(false)
-            {
-                let last = model.input_committed.pop().unwrap();
-                let Ok((l, v, Some(tail))) = hangeul::decompose_char(&last) else {
-                    todo!()
-                };
-                let (first_tail, second_tail) = split_final_jamo(tail);
-
-                let base = first_tail
-                    .map(|t1| hangeul::compose_char(&l, &v, Some(&t1)).unwrap())
-                    .unwrap_or_else(|| hangeul::compose_char(&l, &v, None).unwrap());
-
-                model.input_committed.push(base);
-                model.input_composing.clear();
-                model.input_composing.push(second_tail);
-                model.input_committed.push(*ch);
-                return;
-            }
+    if let WindowEvent::ModifiersChanged(modifiers) = event {
+        model.modifiers = *modifiers;
+    }
+
+    if let nannou::winit::event::WindowEvent::Focused(focused) = event {
+        if *focused {
+            model.input_focus_next_frame = true;
+        }
+    }
+}
+
+fn view_input(_app: &App, model: &Model, frame: Frame) {
+    model.egui.draw_to_frame(&frame).unwrap();
+}
+
+/// Called when Enter is pressed.
+fn handle_enter_commit(model: &mut Model) {
+    let final_line = model.composer.submit();
+    model.input_history.push(final_line);
+}
 
-            // B) Case: it’s fully in your composing buffer as one syllable
-            let (clusters, _spans) = cluster_jamo_with_spans(&model.input_composing);
-            //if clusters.len() == 1 {
-            if let Some(syl) = collapse_to_syllable(&clusters) {
-                if hangeul::ends_with_jongseong(&syl.to_string()).unwrap_or(false) {
-                    let Ok((l, v, Some(tail))) = hangeul::decompose_char(&syl) else {
-                        todo!()
-                    };
-                    let (first_tail, second_tail) = split_final_jamo(tail);
-
-                    let base = first_tail
-                        .map(|t1| hangeul::compose_char(&l, &v, Some(&t1)).unwrap())
-                        .unwrap_or_else(|| hangeul::compose_char(&l, &v, None).unwrap());
-
-                    model.input_committed.push(base);
-                    model.input_composing.clear();
-                    model.input_composing.push(second_tail);
-                    model.input_composing.push(*ch);
-                    return;
+/// Collect what the composer did since the last frame. Everything that
+/// reacts to typing reads `model.events` during this frame.
+fn handle_composer_events(model: &mut Model) {
+    model.events = model.composer.drain_events();
+    model.syllables.clear();
+
+    // Jamo sound on the keystroke, without waiting for the jangdan
+    if let Some(stream) = &model.audio_stream {
+        let sounds = jamo_sounds(&model.events, &model.sonifier);
+        if !sounds.is_empty() {
+            let beat_seconds = model.jangdan.beat_seconds();
+            let sent = stream.send(move |synth: &mut Synth| {
+                for sound in &sounds {
+                    synth.play_jamo(sound, beat_seconds);
                 }
-                //}
+            });
+            if sent.is_err() && model.verbose {
+                println!("Audio: stream closed");
             }
         }
+    }
 
-        // If not Hangeul jamo, commit immediately
-        let code = *ch as u32;
-        if !hangeul::is_jamo(code) && !hangeul::is_compat_jamo(code) {
-            for c in &model.input_composing {
-                model.input_committed.push(*c);
-            }
-            model.input_composing.clear();
-            model.input_committed.push(*ch);
-        } else {
-            // jamo -> push into buffer
-            model.input_composing.push(*ch);
-            if model.input_composing.len() > 5 {
-                let dropped = model.input_composing.remove(0);
-                model.input_committed.push(dropped);
-            }
+    for event in &model.events {
+        model.osc_sender.send_event(event);
 
-            // commit any prefix *clusters* that can’t extend
-            loop {
-                let (clusters, spans) = cluster_jamo_with_spans(&model.input_composing);
-                // if the *whole* clustered buffer is a valid syllable, stop
-                if collapse_to_syllable(&clusters).is_some() {
-                    break;
+        match event {
+            ComposerEvent::SyllableCommitted(text) => {
+                let features = analyze(text);
+                if model.verbose {
+                    for syllable in &features {
+                        println!("{:?} {:?}", syllable, syllable.vector());
+                    }
                 }
-                // otherwise find the longest prefix that *is* a syllable
-                let mut did = false;
-                for i in (1..clusters.len()).rev() {
-                    if let Some(syll) = collapse_to_syllable(&clusters[..i]) {
-                        // commit that syllable
-                        model.input_committed.push(syll);
-
-                        // remove exactly sum(spans[0..i]) raw chars
-                        let raw_to_remove: usize = spans[..i].iter().sum();
-                        model.input_composing.drain(0..raw_to_remove);
-                        did = true;
-                        break;
+                model.syllables.extend(features);
+            }
+            ComposerEvent::LineSubmitted(line) => {
+                model.predictor.learn(line);
+                // The improviser's own lines would only echo back
+                if !model.improvising {
+                    for stanza_line in line.lines() {
+                        model.ngram.learn(stanza_line);
                     }
                 }
-                if !did {
-                    break;
+                let line = to_normal_form(line, model.export_form);
+                println!("Input submitted: {}", line);
+            }
+            _ => {
+                if model.verbose {
+                    println!("{:?}", event);
                 }
             }
         }
     }
 
-    if let nannou::winit::event::WindowEvent::Focused(focused) = event {
-        if *focused {
-            model.input_focus_next_frame = true;
-        }
+    // The music follows how the line is said, not how it is spelled
+    model.said = pronounce_committed(model);
+    if model.verbose && !model.said.text.is_empty() {
+        println!("Pronounced: {} {:?}", model.said.text, model.said.rules);
+    }
+    for note in model.sonifier.notes(&analyze(&model.said.text)) {
+        model.note_queue.push(note);
     }
 }
 
-fn view_input(_app: &App, model: &Model, frame: Frame) {
-    model.egui.draw_to_frame(&frame).unwrap();
+/// How this frame's committed syllables sound, read at the end of the line
+/// they went into.
+fn pronounce_committed(model: &Model) -> Pronunciation {
+    let mut committed = String::new();
+    let mut line = None;
+    for event in &model.events {
+        match event {
+            ComposerEvent::SyllableCommitted(text) => committed.push_str(text),
+            ComposerEvent::LineSubmitted(submitted) => line = Some(submitted.as_str()),
+            _ => {}
+        }
+    }
+    if committed.is_empty() {
+        return Pronunciation::default();
+    }
+
+    // A submitted line has already left the composer
+    let context = line.unwrap_or(model.composer.before_cursor());
+    let context = if context.ends_with(&committed) {
+        context
+    } else {
+        // something else was typed after it this frame; read it alone
+        committed.as_str()
+    };
+    pronounce_tail(context, committed.chars().count())
 }
 
-/// Collapse at most three elements (choseong, jungseong, jongseong) into one syllable.
-fn collapse_to_syllable(clustered: &[char]) -> Option<char> {
-    match clustered.len() {
-        0 => None,
-        1 => Some(clustered[0]),
-        2 => hangeul::compose_char(&clustered[0], &clustered[1], None).ok(),
-        3 => hangeul::compose_char(&clustered[0], &clustered[1], Some(&clustered[2])).ok(),
-        _ => None,
-    }
-}
-
-/// Try combining two simple jungseong into one compound vowel.
-fn try_combine_vowel(a: char, b: char) -> Option<char> {
-    match (a, b) {
-        ('ㅗ', 'ㅏ') => Some('ㅘ'),
-        ('ㅗ', 'ㅐ') => Some('ㅙ'),
-        ('ㅗ', 'ㅣ') => Some('ㅚ'),
-        ('ㅜ', 'ㅓ') => Some('ㅝ'),
-        ('ㅜ', 'ㅔ') => Some('ㅞ'),
-        ('ㅜ', 'ㅣ') => Some('ㅟ'),
-        ('ㅡ', 'ㅣ') => Some('ㅢ'),
-        _ => None,
-    }
-}
-
-/// Try combining two simple jongseong into one compound final.
-fn try_combine_final(a: char, b: char) -> Option<char> {
-    match (a, b) {
-        ('ㄱ', 'ㅅ') => Some('ㄳ'),
-        ('ㄴ', 'ㅈ') => Some('ㄵ'),
-        ('ㄴ', 'ㅎ') => Some('ㄶ'),
-        ('ㄹ', 'ㄱ') => Some('ㄺ'),
-        ('ㄹ', 'ㅁ') => Some('ㄻ'),
-        ('ㄹ', 'ㅂ') => Some('ㄼ'),
-        ('ㄹ', 'ㅅ') => Some('ㄽ'),
-        ('ㄹ', 'ㅌ') => Some('ㄾ'),
-        ('ㄹ', 'ㅍ') => Some('ㄿ'),
-        ('ㄹ', 'ㅎ') => Some('ㅀ'),
-        ('ㅂ', 'ㅅ') => Some('ㅄ'),
-        _ => None,
-    }
-}
-
-/// Returns (clusters, spans) where:
-///  - `clusters[k]` is the k-th merged jamo (e.g. ㄹ+ㄱ → ㄺ), and
-///  - `spans[k]` is how many raw characters that cluster consumed (1 or 2).
-fn cluster_jamo_with_spans(raw: &[char]) -> (Vec<char>, Vec<usize>) {
-    let mut clusters = Vec::new();
-    let mut spans = Vec::new();
-    let mut i = 0;
-    while i < raw.len() {
-        // try double‑vowel first
-        if i + 1 < raw.len() {
-            if let Some(v) = try_combine_vowel(raw[i], raw[i + 1]) {
-                clusters.push(v);
-                spans.push(2);
-                i += 2;
-                continue;
-            }
-        }
-        // try double‑final
-        if i + 1 < raw.len() {
-            if let Some(f) = try_combine_final(raw[i], raw[i + 1]) {
-                clusters.push(f);
-                spans.push(2);
-                i += 2;
-                continue;
+/// Run the jangdan and let waiting notes land on its strokes.
+fn run_jangdan(model: &mut Model, dt: f32) {
+    model.ticks = model.jangdan.update(dt);
+    model.notes.clear();
+
+    for tick in &model.ticks {
+        if let Some(note) = model.note_queue.release(tick) {
+            if model.verbose {
+                println!("{:?} on {:?}", note, tick);
             }
+            model.recording.record(tick.at, note);
+            model.notes.push(note);
         }
-        // else single jamo
-        clusters.push(raw[i]);
-        spans.push(1);
-        i += 1;
-    }
-    (clusters, spans)
-}
-
-/// Given a final jamo (possibly compound), return (first, second).
-/// If it isn’t compound, we interpret that as (no‑final, final).
-fn split_final_jamo(j: char) -> (Option<char>, char) {
-    match j {
-        'ㄳ' => (Some('ㄱ'), 'ㅅ'),
-        'ㄵ' => (Some('ㄴ'), 'ㅈ'),
-        'ㄶ' => (Some('ㄴ'), 'ㅎ'),
-        'ㄺ' => (Some('ㄹ'), 'ㄱ'),
-        'ㄻ' => (Some('ㄹ'), 'ㅁ'),
-        'ㄼ' => (Some('ㄹ'), 'ㅂ'),
-        'ㄽ' => (Some('ㄹ'), 'ㅅ'),
-        'ㄾ' => (Some('ㄹ'), 'ㅌ'),
-        'ㄿ' => (Some('ㄹ'), 'ㅍ'),
-        'ㅀ' => (Some('ㄹ'), 'ㅎ'),
-        'ㅄ' => (Some('ㅂ'), 'ㅅ'),
-        // not a compound final
-        other if hangeul::is_jaeum(other as u32) => (None, other),
-        _ => (None, j),
-    }
-}
-
-fn finalize_composing_buffer(model: &mut Model) {
-    let (clusters, _) = cluster_jamo_with_spans(&model.input_composing);
-
-    if let Some(syllable) = collapse_to_syllable(&clusters) {
-        model.input_committed.push(syllable);
-    } else {
-        for c in clusters {
-            model.input_committed.push(c);
+        // after the note, so the first note's own stroke is kept
+        model.recording.record_stroke(tick.at, tick.stroke);
+    }
+}
+
+/// Lift the background a little on this frame's 장구 strokes. A flash or
+/// fade cued over OSC is left to run.
+fn pulse_background(app: &App, model: &mut Model) {
+    let accent = model
+        .ticks
+        .iter()
+        .map(|tick| tick.stroke.accent())
+        .fold(0.0, f32::max);
+    if accent == 0.0 || model.background.is_animating() {
+        return;
+    }
+    let base = model.background.get_current_color();
+    let lift = 0.12 * accent;
+    let color = rgb(
+        (base.red + lift).min(1.0),
+        (base.green + lift).min(1.0),
+        (base.blue + lift).min(1.0),
+    );
+    // gone before the next 소박
+    let (_, subdivisions) = model.jangdan.jangdan().meter();
+    let duration = 0.8 * model.jangdan.beat_seconds() / subdivisions as f32;
+    model.background.flash(color, duration, app.time);
+}
+
+/// Write the notes played so far to a MIDI file in the output directory.
+fn export_midi(model: &Model) {
+    if model.recording.is_empty() {
+        return;
+    }
+    match model.recording.save(&model.output_dir) {
+        Ok(path) => println!(
+            "MIDI: {} notes saved to {}",
+            model.recording.len(),
+            path.display()
+        ),
+        Err(e) => println!(
+            "MIDI: could not save to {}: {}",
+            model.output_dir.display(),
+            e
+        ),
+    }
+}
+
+/// Open the default output device for the live synth. Without one the
+/// app logs it and runs silent.
+fn open_audio(config: &AudioConfig) -> Option<audio::Stream<Synth>> {
+    if !config.live {
+        return None;
+    }
+    let host = audio::Host::new();
+    let synth = Synth::new(config.sample_rate, config.volume);
+    let stream = match host.new_output_stream(synth).render(render_audio).build() {
+        Ok(stream) => stream,
+        Err(e) => {
+            println!("Audio: no output device, continuing without sound: {}", e);
+            return None;
         }
+    };
+    if let Err(e) = stream.play() {
+        println!(
+            "Audio: could not start output, continuing without sound: {}",
+            e
+        );
+        return None;
     }
-    model.input_composing.clear();
+    Some(stream)
 }
 
-/// Called when Enter is pressed.
-fn handle_enter_commit(model: &mut Model) {
-    finalize_composing_buffer(model);
-    let final_line = model.input_committed.clone();
-    model.input_history.push(final_line);
-    model.input_committed.clear();
+// Runs on the audio thread: the same sample on every channel.
+fn render_audio(synth: &mut Synth, buffer: &mut audio::Buffer) {
+    if synth.sample_rate() != buffer.sample_rate() {
+        synth.set_sample_rate(buffer.sample_rate());
+    }
+    for frame in buffer.frames_mut() {
+        let sample = synth.next_sample();
+        for channel in frame {
+            *channel = sample;
+        }
+    }
 }
 
-/// Called when Space or punctuation is typed.
-fn handle_punctuation_commit(model: &mut Model, ch: char) {
-    finalize_composing_buffer(model);
-    model.input_committed.push(ch);
+/// The master volume, or silence when muted, to the audio thread.
+fn send_volume(model: &Model) {
+    let volume = if model.muted { 0.0 } else { model.volume };
+    if let Some(stream) = &model.audio_stream {
+        if stream
+            .send(move |synth: &mut Synth| synth.set_volume(volume))
+            .is_err()
+        {
+            println!("Audio: stream closed");
+        }
+    }
 }
 
-fn handle_backspace(model: &mut Model) {
-    // Step 1: pop from input_composing if it has anything
-    if model.input_composing.pop().is_some() {
-        println!("Composing pop");
+/// Render the session so far to a WAV file, off the main thread.
+fn render_wav(model: &Model) {
+    if model.recording.is_empty() {
         return;
     }
+    let recording = model.recording.clone();
+    let dir = model.output_dir.clone();
+    // the control window's volume; muting the live sound doesn't silence the file
+    let (sample_rate, volume) = (model.audio_config.sample_rate, model.volume);
+    let spawned = std::thread::Builder::new()
+        .name("wav_render".to_owned())
+        .spawn(
+            move || match save_wav(&recording, &dir, sample_rate, volume) {
+                Ok(path) => println!("WAV: saved to {}", path.display()),
+                Err(e) => println!("WAV: could not save to {}: {}", dir.display(), e),
+            },
+        );
+    if let Err(e) = spawned {
+        println!("WAV: could not start rendering: {}", e);
+    }
+}
 
-    // Case 2: composing is empty → decompose last syllable from committed
-    if let Some(last) = model.input_committed.pop() {
-        println!("Popped from committed: {}", last);
-        if let Ok((lead, vowel, final_opt)) = hangeul::decompose_char(&last) {
-            let mut jamo = vec![lead, vowel];
-            if let Some(j) = final_opt {
-                jamo.push(j);
-            }
+/// Replace the current word with its completion, if there is one.
+fn complete_word(model: &mut Model) {
+    let word = model.composer.current_word();
+    if let Some(completion) = model.predictor.complete(&word) {
+        let completion = completion.to_owned();
+        model
+            .composer
+            .replace_before_cursor(word.chars().count(), &completion);
+    }
+}
 
-            model.input_composing = jamo;
-            model.input_composing.pop();
-            // The next backspace will remove the last jamo from this buffer
-        } else {
-            // If it's not decomposable, just treat it as a single pop
-            // (e.g., English letters, symbols)
-            // Already popped above
+/// Keys for the open Hanja list. Returns whether the key was used.
+fn handle_hanja_key(model: &mut Model, key: VirtualKeyCode) -> bool {
+    let Some(picker) = &mut model.hanja_picker else {
+        return false;
+    };
+    match key {
+        VirtualKeyCode::Left | VirtualKeyCode::Up => picker.prev(),
+        VirtualKeyCode::Right | VirtualKeyCode::Down => picker.next(),
+        VirtualKeyCode::Return => {
+            let index = picker.selected;
+            pick_hanja(&mut model.hanja_picker, &mut model.composer, index);
+        }
+        VirtualKeyCode::Back | VirtualKeyCode::F9 | VirtualKeyCode::Kanji => {
+            model.hanja_picker = None;
+        }
+        _ => return false,
+    }
+    true
+}
+
+/// Replace the word before the cursor with a candidate and close the list.
+fn pick_hanja(picker: &mut Option<HanjaPicker>, composer: &mut HangeulComposer, index: usize) {
+    if let Some(picker) = picker.take() {
+        if let Some(candidate) = picker.candidates.get(index) {
+            composer.replace_before_cursor(picker.reading_len, &candidate.hanja);
         }
     }
-    if let Some(last) = model.input_committed.pop() {
-        // Try to decompose the last character
-        if let Ok((lead, vowel, final_opt)) = hangeul::decompose_char(&last) {
-            model.input_composing.clear();
-            model.input_composing.push(lead);
-            model.input_composing.push(vowel);
-            if let Some(final_jamo) = final_opt {
-                model.input_composing.push(final_jamo);
+}
+
+/// Act on the OSC commands received since the last frame.
+fn handle_osc(app: &App, model: &mut Model) {
+    let Some(receiver) = &model.osc_receiver else {
+        return;
+    };
+    for command in receiver.drain() {
+        if model.verbose {
+            println!("OSC: {:?}", command);
+        }
+        match command {
+            OscCommand::TextAppend(text) => model.composer.push_str(&text),
+            OscCommand::TextClear => model.composer.clear(),
+            OscCommand::TextSubmit => handle_enter_commit(model),
+            OscCommand::BgFlash {
+                color: [r, g, b],
+                duration,
+            } => model.background.flash(rgb(r, g, b), duration, app.time),
+            OscCommand::BgFade {
+                color: [r, g, b],
+                duration,
+            } => model
+                .background
+                .color_fade(rgb(r, g, b), duration, app.time),
+            OscCommand::Verbose(on) => {
+                if on && !model.verbose {
+                    init_fps(app, model);
+                }
+                model.verbose = on;
             }
-            // Remove the last jamo
-            model.input_composing.pop();
-        } else {
-            // If not a decomposable Hangul syllable, do nothing or treat as raw pop
         }
     }
 }
 
-/// Determines if a character is considered punctuation or a space.
-fn is_punctuation(c: &char) -> bool {
-    matches!(
-        c,
-        '!' | '"'
-            | '#'
-            | '$'
-            | '%'
-            | '&'
-            | '\''
-            | '('
-            | ')'
-            | '*'
-            | '+'
-            | ','
-            | '-'
-            | '.'
-            | '/'
-            | ':'
-            | ';'
-            | '<'
-            | '='
-            | '>'
-            | '?'
-            | '@'
-            | '['
-            | '\\'
-            | ']'
-            | '^'
-            | '_'
-            | '`'
-            | '{'
-            | '|'
-            | '}'
-            | '~'
-            | ' '
-    )
+/// Start performing the autopilot text, or stop a running performance.
+fn toggle_autopilot(model: &mut Model) {
+    model.improvising = false;
+    if model.autotypist.take().is_some() {
+        println!("Autopilot stopped");
+        return;
+    }
+    match &model.autopilot_text {
+        Some(text) => {
+            model.autotypist = Some(AutoTypist::new(text, &model.typing_style, &mut model.rng));
+            println!("Autopilot started");
+        }
+        None => println!("Autopilot: no text file set in config.toml"),
+    }
+}
+
+/// Play the keystrokes the autopilot has due this frame.
+fn run_autopilot(model: &mut Model, dt: f32) {
+    if model.improvising && model.autotypist.is_none() {
+        improvise_line(model);
+    }
+    let Some(typist) = &mut model.autotypist else {
+        return;
+    };
+    let keys = typist.update(dt);
+    if typist.is_finished() {
+        model.autotypist = None;
+    }
+
+    for key in keys {
+        match key {
+            Keystroke::Char(ch) => handle_typed_char(model, ch),
+            Keystroke::Backspace => handle_backspace(model),
+            Keystroke::Enter => handle_enter_commit(model),
+        }
+    }
+}
+
+/// Start or stop making up lines. Either way, any typing in progress stops.
+fn set_improvising(model: &mut Model, on: bool) {
+    model.improvising = on;
+    model.autotypist = None;
+    println!("Improviser {}", if on { "on" } else { "off" });
+}
+
+/// Make up the next line and hand it to the autopilot to type.
+fn improvise_line(model: &mut Model) {
+    let line = model.ngram.generate(
+        &mut model.improv_rng,
+        model.improv_temperature,
+        model.improv_max_length,
+    );
+    if line.is_empty() {
+        println!("Improviser: nothing learned yet");
+        model.improvising = false;
+        return;
+    }
+    let typist = AutoTypist::new(&line, &model.improv_style, &mut model.improv_rng);
+    model.autotypist = Some(typist.delayed(model.improv_style.line_pause));
+}
+
+/// A jamo or punctuation mark, from the keyboard or the autopilot.
+fn handle_typed_char(model: &mut Model, ch: char) {
+    model.composer.push_char(ch);
+}
+
+fn handle_backspace(model: &mut Model) {
+    if model.verbose {
+        println!(
+            "before: {}, {:?}",
+            model.composer.committed(),
+            model.composer.composing()
+        );
+    }
+    model.composer.backspace();
+    if model.verbose {
+        println!(
+            "after: {}, {:?}",
+            model.composer.committed(),
+            model.composer.composing()
+        );
+    }
 }
@@ -0,0 +1,369 @@
+// src/music/jangdan.rs
+//
+// Jangdan (장단): the rhythmic cycles of 판소리 and 민요, as a tempo clock.
+// `bpm` counts 박 (main beats); each 박 splits into 소박 of two or three in
+// the compound cycles. The clock ticks once per 소박 and reports the 장구
+// stroke there:
+//   덩 합장단 (both heads), 쿵 북편 (left hand), 덕 채편 (stick), - rest
+// Patterns are the plain textbook skeletons, without ornaments.
+//
+// Syllables typed off the beat wait in a `Quantizer` for the next stroke.
+
+use serde::Deserialize;
+use std::collections::VecDeque;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Jangdan {
+    Jinyangjo,
+    #[default]
+    Jungmori,
+    Jungjungmori,
+    Jajinmori,
+    Hwimori,
+    Gutgeori,
+    Semachi,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Stroke {
+    Hap,  // 덩
+    Buk,  // 쿵
+    Chae, // 덕
+    Rest,
+}
+
+impl Stroke {
+    fn parse(token: &str) -> Option<Self> {
+        match token {
+            "덩" => Some(Stroke::Hap),
+            "쿵" => Some(Stroke::Buk),
+            "덕" => Some(Stroke::Chae),
+            "-" => Some(Stroke::Rest),
+            _ => None,
+        }
+    }
+
+    /// Weight of the stroke, 합 strongest, 0.0 for a rest.
+    pub fn accent(self) -> f32 {
+        match self {
+            Stroke::Hap => 1.0,
+            Stroke::Buk => 0.8,
+            Stroke::Chae => 0.6,
+            Stroke::Rest => 0.0,
+        }
+    }
+}
+
+impl Jangdan {
+    pub const ALL: [Jangdan; 7] = [
+        Jangdan::Jinyangjo,
+        Jangdan::Jungmori,
+        Jangdan::Jungjungmori,
+        Jangdan::Jajinmori,
+        Jangdan::Hwimori,
+        Jangdan::Gutgeori,
+        Jangdan::Semachi,
+    ];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Jangdan::Jinyangjo => "진양조",
+            Jangdan::Jungmori => "중모리",
+            Jangdan::Jungjungmori => "중중모리",
+            Jangdan::Jajinmori => "자진모리",
+            Jangdan::Hwimori => "휘모리",
+            Jangdan::Gutgeori => "굿거리",
+            Jangdan::Semachi => "세마치",
+        }
+    }
+
+    /// 박 per cycle and 소박 per 박.
+    pub fn meter(self) -> (usize, usize) {
+        match self {
+            Jangdan::Jinyangjo => (24, 1),
+            Jangdan::Jungmori => (12, 1),
+            Jangdan::Jungjungmori => (4, 3),
+            Jangdan::Jajinmori => (4, 3),
+            Jangdan::Hwimori => (4, 2),
+            Jangdan::Gutgeori => (4, 3),
+            Jangdan::Semachi => (3, 3),
+        }
+    }
+
+    // 구음, one token per 소박; bars mark 박 (or 각 in 진양조).
+    fn notation(self) -> &'static str {
+        match self {
+            Jangdan::Jinyangjo => "덩 - - - - - | 쿵 - - - - - | 덕 - - 덕 - - | 쿵 - - - 덕 덕",
+            Jangdan::Jungmori => "덩 - 쿵 덕 쿵 - | 덕 - 쿵 덕 쿵 -",
+            Jangdan::Jungjungmori => "덩 - 덕 | 쿵 덕 - | 쿵 - 덕 | 쿵 덕 -",
+            Jangdan::Jajinmori => "덩 - 덕 | - 덕 - | 쿵 - 덕 | - 덕 -",
+            Jangdan::Hwimori => "덩 - | 덕 쿵 | - 덕 | 쿵 -",
+            Jangdan::Gutgeori => "덩 - 덕 | 쿵 덕 덕 | 쿵 - 덕 | 쿵 덕 덕",
+            Jangdan::Semachi => "덩 - 덩 | - 덕 쿵 | - 덕 -",
+        }
+    }
+
+    /// The stroke on every 소박 of the cycle.
+    pub fn pattern(self) -> Vec<Stroke> {
+        self.notation()
+            .split_whitespace()
+            .filter_map(Stroke::parse)
+            .collect()
+    }
+
+    /// The next jangdan in `ALL`, wrapping round.
+    pub fn next(self) -> Self {
+        let at = Self::ALL.iter().position(|j| *j == self).unwrap_or(0);
+        Self::ALL[(at + 1) % Self::ALL.len()]
+    }
+}
+
+/// One 소박 of the cycle, as the clock passes it.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Tick {
+    pub at: f64, // 박 since the clock started
+    pub cycle: u64,
+    pub step: usize, // 소박 within the cycle
+    pub beat: usize, // 박 within the cycle
+    pub stroke: Stroke,
+}
+
+#[derive(Debug, Clone)]
+pub struct JangdanClock {
+    jangdan: Jangdan,
+    pattern: Vec<Stroke>,
+    bpm: f32,
+    position: f64,  // in 소박 since the cycle started
+    next_tick: u64, // first 소박 not yet reported
+    elapsed: f64,   // 박 since the clock started, across jangdan changes
+}
+
+impl JangdanClock {
+    pub fn new(jangdan: Jangdan, bpm: f32) -> Self {
+        Self {
+            jangdan,
+            pattern: jangdan.pattern(),
+            bpm: bpm.max(1.0),
+            position: 0.0,
+            next_tick: 0,
+            elapsed: 0.0,
+        }
+    }
+
+    pub fn jangdan(&self) -> Jangdan {
+        self.jangdan
+    }
+
+    /// Switch cycles; the new one starts from its first beat.
+    pub fn set_jangdan(&mut self, jangdan: Jangdan) {
+        let elapsed = self.elapsed;
+        *self = Self::new(jangdan, self.bpm);
+        self.elapsed = elapsed;
+    }
+
+    pub fn bpm(&self) -> f32 {
+        self.bpm
+    }
+
+    pub fn set_bpm(&mut self, bpm: f32) {
+        self.bpm = bpm.max(1.0);
+    }
+
+    /// Seconds per 박.
+    pub fn beat_seconds(&self) -> f32 {
+        60.0 / self.bpm
+    }
+
+    /// Advance by `dt` seconds and return the 소박 passed, in order.
+    pub fn update(&mut self, dt: f32) -> Vec<Tick> {
+        let (_, subdivisions) = self.jangdan.meter();
+        let beats = dt as f64 * self.bpm as f64 / 60.0;
+        // time at the start of the cycle, in 박
+        let cycle_start = self.elapsed - self.position / subdivisions as f64;
+        self.position += beats * subdivisions as f64;
+        self.elapsed += beats;
+
+        let mut ticks = Vec::new();
+        while self.next_tick as f64 <= self.position {
+            let len = self.pattern.len() as u64;
+            let step = (self.next_tick % len) as usize;
+            ticks.push(Tick {
+                at: cycle_start + self.next_tick as f64 / subdivisions as f64,
+                cycle: self.next_tick / len,
+                step,
+                beat: step / subdivisions,
+                stroke: self.pattern[step],
+            });
+            self.next_tick += 1;
+        }
+        ticks
+    }
+
+    /// 박 since the clock started.
+    pub fn elapsed(&self) -> f64 {
+        self.elapsed
+    }
+
+    /// The 박 the clock is in, from 0.
+    pub fn beat(&self) -> usize {
+        let (_, subdivisions) = self.jangdan.meter();
+        self.step() / subdivisions
+    }
+
+    /// How far through the current 박, 0.0 - 1.0.
+    pub fn beat_phase(&self) -> f32 {
+        let (_, subdivisions) = self.jangdan.meter();
+        (self.position / subdivisions as f64).fract() as f32
+    }
+
+    /// How far through the current 소박, 0.0 - 1.0.
+    pub fn step_phase(&self) -> f32 {
+        self.position.fract() as f32
+    }
+
+    /// The accent of the stroke the clock is on, dying away over its 소박;
+    /// 0.0 on rests. For visuals that breathe with the jangdan.
+    pub fn pulse(&self) -> f32 {
+        self.stroke().accent() * (1.0 - self.step_phase())
+    }
+
+    /// How far through the cycle, 0.0 - 1.0.
+    pub fn phase(&self) -> f32 {
+        let len = self.pattern.len() as f64;
+        (self.position.rem_euclid(len) / len) as f32
+    }
+
+    /// The stroke of the 소박 the clock is in.
+    pub fn stroke(&self) -> Stroke {
+        self.pattern[self.step()]
+    }
+
+    fn step(&self) -> usize {
+        self.position.floor() as usize % self.pattern.len()
+    }
+}
+
+// Most syllables kept waiting; older ones are dropped past this.
+const MAX_PENDING: usize = 16;
+
+/// Holds things typed between strokes and lets one out on each stroke.
+#[derive(Debug, Clone)]
+pub struct Quantizer<T> {
+    pending: VecDeque<T>,
+}
+
+impl<T> Default for Quantizer<T> {
+    fn default() -> Self {
+        Self {
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<T> Quantizer<T> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, item: T) {
+        self.pending.push_back(item);
+        if self.pending.len() > MAX_PENDING {
+            self.pending.pop_front();
+        }
+    }
+
+    /// What lands on this tick: the oldest waiting item, if the tick is a
+    /// stroke rather than a rest.
+    pub fn release(&mut self, tick: &Tick) -> Option<T> {
+        if tick.stroke == Stroke::Rest {
+            return None;
+        }
+        self.pending.pop_front()
+    }
+
+    pub fn len(&self) -> usize {
+        self.pending.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.pending.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_patterns_fill_the_meter() {
+        for jangdan in Jangdan::ALL {
+            let (beats, subdivisions) = jangdan.meter();
+            let pattern = jangdan.pattern();
+            assert_eq!(pattern.len(), beats * subdivisions, "{}", jangdan.name());
+            // every cycle opens on 합장단
+            assert_eq!(pattern[0], Stroke::Hap, "{}", jangdan.name());
+        }
+    }
+
+    #[test]
+    fn test_clock_ticks() {
+        // 중모리 at 60 bpm: one 소박 a second
+        let mut clock = JangdanClock::new(Jangdan::Jungmori, 60.0);
+        let ticks = clock.update(0.5);
+        assert_eq!(ticks.len(), 1);
+        assert_eq!(ticks[0].stroke, Stroke::Hap);
+        assert!((clock.beat_phase() - 0.5).abs() < 1e-6);
+        assert!((clock.pulse() - 0.5).abs() < 1e-6);
+
+        let ticks = clock.update(3.0);
+        let strokes: Vec<Stroke> = ticks.iter().map(|t| t.stroke).collect();
+        assert_eq!(strokes, vec![Stroke::Rest, Stroke::Buk, Stroke::Chae]);
+        assert_eq!(clock.beat(), 3);
+
+        // round to the next cycle
+        let ticks = clock.update(9.0);
+        assert_eq!(ticks.last().unwrap().cycle, 1);
+        assert_eq!(ticks.last().unwrap().step, 0);
+        assert!(clock.phase() < 0.1);
+    }
+
+    #[test]
+    fn test_compound_beats() {
+        // 자진모리 at 60 bpm: three 소박 a second
+        let mut clock = JangdanClock::new(Jangdan::Jajinmori, 60.0);
+        let ticks = clock.update(1.0);
+        let beats: Vec<usize> = ticks.iter().map(|t| t.beat).collect();
+        assert_eq!(beats, vec![0, 0, 0, 1]);
+        assert_eq!(clock.beat(), 1);
+        assert!((ticks[2].at - 2.0 / 3.0).abs() < 1e-9);
+
+        // changing jangdan restarts the cycle but not the time
+        clock.update(0.5);
+        clock.set_jangdan(Jangdan::Semachi);
+        let ticks = clock.update(0.1);
+        assert_eq!(ticks[0].step, 0);
+        assert!((ticks[0].at - 1.5).abs() < 1e-9);
+        assert!((clock.elapsed() - 1.6).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_quantizer_waits_for_a_stroke() {
+        let mut clock = JangdanClock::new(Jangdan::Jungmori, 60.0);
+        let mut quantizer = Quantizer::new();
+        quantizer.push('가');
+        quantizer.push('나');
+
+        let ticks = clock.update(1.5); // 덩 -
+        let released: Vec<char> = ticks.iter().filter_map(|t| quantizer.release(t)).collect();
+        assert_eq!(released, vec!['가']);
+        let ticks = clock.update(1.0); // 쿵
+        assert_eq!(quantizer.release(&ticks[0]), Some('나'));
+        assert!(quantizer.is_empty());
+
+        for _ in 0..20 {
+            quantizer.push('다');
+        }
+        assert_eq!(quantizer.len(), MAX_PENDING);
+    }
+}
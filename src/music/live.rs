@@ -0,0 +1,138 @@
+// src/music/live.rs
+//
+// Typing as it happens, for the real-time voice. Unlike the notes, which
+// wait for a stroke of the jangdan, these sound on the keystroke:
+//  - a consonant taps its own pitch, whether it went in as initial or final
+//  - a vowel holds the note of the syllable it forms
+// A vowel with no syllable to form (ㅏ alone) stays silent.
+
+use super::sonifier::{Note, Sonifier};
+use crate::services::composer_event::ComposerEvent;
+use crate::services::jamo::JamoRole;
+use crate::services::syllable_analysis::SyllableFeatures;
+
+const CONSONANT_VELOCITY: u8 = 90;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum JamoSound {
+    Consonant { pitch: u8, velocity: u8 },
+    Vowel(Note),
+}
+
+/// What a frame's composer events sound like, in order.
+pub fn jamo_sounds(events: &[ComposerEvent], sonifier: &Sonifier) -> Vec<JamoSound> {
+    let mut sounds = Vec::new();
+    let mut vowel_typed = false;
+    for event in events {
+        match event {
+            ComposerEvent::JamoTyped {
+                role: JamoRole::Jungseong,
+                ..
+            } => vowel_typed = true,
+            ComposerEvent::JamoTyped { jamo, .. } => {
+                vowel_typed = false;
+                if let Some(pitch) = sonifier.consonant_pitch(*jamo) {
+                    sounds.push(JamoSound::Consonant {
+                        pitch,
+                        velocity: CONSONANT_VELOCITY,
+                    });
+                }
+            }
+            // the syllable the vowel just made
+            ComposerEvent::SyllableFormed(text) if vowel_typed => {
+                vowel_typed = false;
+                let note = text
+                    .chars()
+                    .last()
+                    .and_then(SyllableFeatures::of)
+                    .and_then(|syllable| sonifier.note_for(&syllable));
+                if let Some(note) = note {
+                    sounds.push(JamoSound::Vowel(note));
+                }
+            }
+            _ => {}
+        }
+    }
+    sounds
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::music::scale::Scale;
+    use crate::music::sonifier::{Articulation, Articulations, Registers};
+    use crate::services::hangeul_composer::HangeulComposer;
+    use std::collections::HashMap;
+
+    fn sonifier() -> Sonifier {
+        let degrees: HashMap<String, i32> = [("ㄱ", 2), ("ㄴ", 3), ("ㄷ", 3), ("ㄹ", -2)]
+            .iter()
+            .map(|(letter, degree)| (letter.to_string(), *degree))
+            .collect();
+        let articulation = Articulation {
+            beats: 1.0,
+            gate: 1.0,
+            velocity: 80,
+        };
+        Sonifier::new(
+            Scale::Pyeongjo,
+            60,
+            &degrees,
+            Registers {
+                yang: 1,
+                yin: -1,
+                neutral: 0,
+            },
+            Articulations {
+                open: articulation,
+                nasal: articulation,
+                liquid: articulation,
+                stop: articulation,
+            },
+        )
+    }
+
+    fn typed(text: &str) -> Vec<ComposerEvent> {
+        let mut composer = HangeulComposer::new();
+        for ch in text.chars() {
+            composer.push_char(ch);
+        }
+        composer.drain_events()
+    }
+
+    #[test]
+    fn test_consonants_tap_vowels_hold() {
+        let sonifier = sonifier();
+        let sounds = jamo_sounds(&typed("ㄱㅏㄴ"), &sonifier);
+        assert_eq!(sounds.len(), 3);
+        assert_eq!(
+            sounds[0],
+            JamoSound::Consonant {
+                pitch: 65,
+                velocity: CONSONANT_VELOCITY
+            }
+        );
+        match sounds[1] {
+            JamoSound::Vowel(note) => {
+                assert_eq!(note.syllable, '가');
+                assert_eq!(note.pitch, 77);
+            }
+            _ => panic!("{:?}", sounds[1]),
+        }
+        // ㄴ as a final taps too
+        assert!(matches!(sounds[2], JamoSound::Consonant { pitch: 67, .. }));
+    }
+
+    #[test]
+    fn test_vowel_moves_a_final() {
+        let sonifier = sonifier();
+        // 달 + ㅏ → 다 / 라: the vowel holds 라
+        let sounds = jamo_sounds(&typed("ㄷㅏㄹㅏ"), &sonifier);
+        match sounds.last() {
+            Some(JamoSound::Vowel(note)) => assert_eq!(note.syllable, '라'),
+            other => panic!("{:?}", other),
+        }
+        // no syllable, no sound
+        assert!(jamo_sounds(&typed("ㅏ"), &sonifier).is_empty());
+    }
+}
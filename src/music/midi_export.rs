@@ -0,0 +1,338 @@
+// src/music/midi_export.rs
+//
+// Records the notes the jangdan clock lets out and writes them as a
+// Standard MIDI File, to take a performance into a DAW. One track per part
+// of the syllable:
+//   초성  the initial's own pitch, a short pluck on the stroke
+//   중성  the melody, moved into the vowel's register
+//   종성  the ending as 장구-ish GM percussion on channel 10
+//         (stop: side stick, nasal: low tom, liquid: tambourine, open: none)
+// Times are in 박 from the clock, so the file keeps the session's tempo.
+// The 장구 strokes are kept too, for rendering the session to audio.
+
+use super::jangdan::Stroke;
+use super::sonifier::Note;
+use crate::services::syllable_analysis::{CodaClass, SyllableFeatures};
+use midly::num::{u15, u24, u28, u4, u7};
+use midly::{Format, Header, MetaMessage, MidiMessage, Smf, Timing, TrackEvent, TrackEventKind};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const TICKS_PER_BEAT: u16 = 480;
+// Longest the 초성 pluck sounds, in 박
+const ONSET_BEATS: f32 = 0.25;
+const PERCUSSION_BEATS: f32 = 0.25;
+
+const ONSET_CHANNEL: u8 = 0;
+const MELODY_CHANNEL: u8 = 1;
+const PERCUSSION_CHANNEL: u8 = 9;
+
+/// GM drum for each ending; open syllables don't sound.
+fn percussion_key(coda: CodaClass) -> Option<u8> {
+    match coda {
+        CodaClass::Stop => Some(37),   // side stick
+        CodaClass::Nasal => Some(45),  // low tom
+        CodaClass::Liquid => Some(54), // tambourine
+        CodaClass::Open => None,
+    }
+}
+
+// A note on or off on one track, at a tick from the start of the file.
+struct Event {
+    tick: u64,
+    on: bool,
+    channel: u8,
+    key: u8,
+    velocity: u8,
+}
+
+#[derive(Debug, Clone)]
+pub struct Recording {
+    bpm: f32,
+    notes: Vec<(f64, Note)>, // 박 since the clock started
+    strokes: Vec<(f64, Stroke)>,
+}
+
+impl Recording {
+    pub fn new(bpm: f32) -> Self {
+        Self {
+            bpm: bpm.max(1.0),
+            notes: Vec::new(),
+            strokes: Vec::new(),
+        }
+    }
+
+    /// Keep `note`, played `at` 박 since the clock started.
+    pub fn record(&mut self, at: f64, note: Note) {
+        self.notes.push((at, note));
+    }
+
+    /// Keep a 장구 stroke of the clock. Rests aren't kept, nor strokes
+    /// before the first note, so an idle clock doesn't pile them up.
+    pub fn record_stroke(&mut self, at: f64, stroke: Stroke) {
+        if stroke != Stroke::Rest && !self.notes.is_empty() {
+            self.strokes.push((at, stroke));
+        }
+    }
+
+    pub fn bpm(&self) -> f32 {
+        self.bpm
+    }
+
+    pub fn notes(&self) -> &[(f64, Note)] {
+        &self.notes
+    }
+
+    /// The strokes from the 박 of the first note until the last note ends.
+    pub fn strokes(&self) -> impl Iterator<Item = &(f64, Stroke)> {
+        let (start, end) = (self.start(), self.end());
+        self.strokes
+            .iter()
+            .filter(move |(at, _)| *at >= start && *at < end)
+    }
+
+    /// The 박 the first note falls in.
+    pub fn start(&self) -> f64 {
+        if self.notes.is_empty() {
+            return 0.0;
+        }
+        self.notes
+            .iter()
+            .map(|(at, _)| *at)
+            .fold(f64::INFINITY, f64::min)
+            .floor()
+    }
+
+    /// When the last note stops, in 박.
+    pub fn end(&self) -> f64 {
+        self.notes
+            .iter()
+            .map(|(at, note)| at + note.beats as f64)
+            .fold(self.start(), f64::max)
+    }
+
+    pub fn len(&self) -> usize {
+        self.notes.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.notes.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.notes.clear();
+        self.strokes.clear();
+    }
+
+    /// The recording as a format 1 file: a tempo track, then 초성, 중성 and
+    /// 종성. The file starts on the 박 of the first note.
+    pub fn to_smf(&self) -> Smf<'static> {
+        let start = self.start();
+        let tick = |beats: f64| ((beats - start).max(0.0) * TICKS_PER_BEAT as f64).round() as u64;
+
+        let mut onsets = Vec::new();
+        let mut melody = Vec::new();
+        let mut percussion = Vec::new();
+        for (at, note) in &self.notes {
+            let sounding = note.beats * note.gate;
+            let velocity = note.velocity.max(1);
+            let push = |events: &mut Vec<Event>, channel, key, beats: f32| {
+                let on = tick(*at);
+                let off = tick(*at + beats as f64).max(on + 1);
+                for (tick, on) in [(on, true), (off, false)] {
+                    events.push(Event {
+                        tick,
+                        on,
+                        channel,
+                        key,
+                        velocity,
+                    });
+                }
+            };
+
+            push(
+                &mut onsets,
+                ONSET_CHANNEL,
+                note.onset,
+                sounding.min(ONSET_BEATS),
+            );
+            push(&mut melody, MELODY_CHANNEL, note.pitch, sounding);
+            let coda = SyllableFeatures::of(note.syllable).map(|f| f.coda);
+            if let Some(key) = coda.and_then(percussion_key) {
+                push(&mut percussion, PERCUSSION_CHANNEL, key, PERCUSSION_BEATS);
+            }
+        }
+
+        let tempo = (60_000_000.0 / self.bpm).round() as u32;
+        let conductor = vec![
+            meta(0, MetaMessage::TrackName(b"autohmjeum")),
+            meta(0, MetaMessage::Tempo(u24::new(tempo))),
+            meta(0, MetaMessage::EndOfTrack),
+        ];
+
+        let mut smf = Smf::new(Header::new(
+            Format::Parallel,
+            Timing::Metrical(u15::new(TICKS_PER_BEAT)),
+        ));
+        smf.tracks.push(conductor);
+        smf.tracks.push(track("초성", onsets));
+        smf.tracks.push(track("중성", melody));
+        smf.tracks.push(track("종성", percussion));
+        smf
+    }
+
+    /// Write the recording into `dir` as `autohmjeum_<unix time>.mid`.
+    pub fn save(&self, dir: &Path) -> io::Result<PathBuf> {
+        let path = session_path(dir, "mid")?;
+        self.to_smf().save(&path)?;
+        Ok(path)
+    }
+}
+
+/// `dir/autohmjeum_<unix time>.<extension>`, creating `dir` if needed.
+pub fn session_path(dir: &Path, extension: &str) -> io::Result<PathBuf> {
+    std::fs::create_dir_all(dir)?;
+    let stamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0);
+    Ok(dir.join(format!("autohmjeum_{}.{}", stamp, extension)))
+}
+
+fn meta(delta: u32, message: MetaMessage<'static>) -> TrackEvent<'static> {
+    TrackEvent {
+        delta: u28::new(delta),
+        kind: TrackEventKind::Meta(message),
+    }
+}
+
+fn track(name: &'static str, mut events: Vec<Event>) -> Vec<TrackEvent<'static>> {
+    // offs before ons on the same tick, so repeated notes retrigger
+    events.sort_by_key(|e| (e.tick, e.on));
+
+    let mut track = vec![meta(0, MetaMessage::TrackName(name.as_bytes()))];
+    let mut last = 0;
+    for e in events {
+        let key = u7::new(e.key);
+        let vel = u7::new(e.velocity);
+        let message = if e.on {
+            MidiMessage::NoteOn { key, vel }
+        } else {
+            MidiMessage::NoteOff { key, vel }
+        };
+        track.push(TrackEvent {
+            delta: u28::new((e.tick - last) as u32),
+            kind: TrackEventKind::Midi {
+                channel: u4::new(e.channel),
+                message,
+            },
+        });
+        last = e.tick;
+    }
+    track.push(meta(0, MetaMessage::EndOfTrack));
+    track
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::music::jangdan::{Jangdan, JangdanClock};
+
+    fn note(syllable: char, pitch: u8, beats: f32) -> Note {
+        Note {
+            syllable,
+            pitch,
+            onset: pitch - 12,
+            velocity: 90,
+            beats,
+            gate: 1.0,
+        }
+    }
+
+    // (absolute tick, channel, key, on) for each note event of a track
+    fn notes_of(track: &[TrackEvent]) -> Vec<(u32, u8, u8, bool)> {
+        let mut tick = 0;
+        let mut notes = Vec::new();
+        for event in track {
+            tick += event.delta.as_int();
+            if let TrackEventKind::Midi { channel, message } = event.kind {
+                match message {
+                    MidiMessage::NoteOn { key, .. } => {
+                        notes.push((tick, channel.as_int(), key.as_int(), true))
+                    }
+                    MidiMessage::NoteOff { key, .. } => {
+                        notes.push((tick, channel.as_int(), key.as_int(), false))
+                    }
+                    _ => {}
+                }
+            }
+        }
+        notes
+    }
+
+    #[test]
+    fn test_tracks() {
+        let mut recording = Recording::new(90.0);
+        recording.record(3.0, note('가', 72, 1.0));
+        recording.record(4.0, note('각', 74, 0.5));
+        let smf = recording.to_smf();
+        assert_eq!(smf.header.format, Format::Parallel);
+        assert_eq!(smf.tracks.len(), 4);
+        assert_eq!(recording.end(), 4.5);
+        assert!(smf.tracks[0].contains(&meta(0, MetaMessage::Tempo(u24::new(666_667)))));
+
+        // starts on the first note's 박
+        assert_eq!(
+            notes_of(&smf.tracks[2]),
+            vec![
+                (0, 1, 72, true),
+                (480, 1, 72, false),
+                (480, 1, 74, true),
+                (720, 1, 74, false),
+            ]
+        );
+        // 초성 plucks are short
+        assert_eq!(notes_of(&smf.tracks[1])[1], (120, 0, 60, false));
+        // only 각 has an ending that sounds
+        assert_eq!(
+            notes_of(&smf.tracks[3]),
+            vec![(480, 9, 37, true), (600, 9, 37, false)]
+        );
+    }
+
+    #[test]
+    fn test_idle_strokes_not_kept() {
+        let mut clock = JangdanClock::new(Jangdan::Jungmori, 120.0);
+        let mut recording = Recording::new(120.0);
+        for tick in clock.update(60.0) {
+            recording.record_stroke(tick.at, tick.stroke);
+        }
+        assert!(recording.strokes.is_empty());
+
+        // kept from the first note on, starting with the stroke it lands on
+        let ticks = clock.update(2.0);
+        let first = ticks.iter().find(|t| t.stroke != Stroke::Rest).unwrap();
+        recording.record(first.at, note('가', 72, 1.0));
+        for tick in &ticks {
+            recording.record_stroke(tick.at, tick.stroke);
+        }
+        assert!(!recording.strokes.is_empty());
+        assert_eq!(recording.strokes().next().unwrap().0, first.at);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let mut recording = Recording::new(60.0);
+        for (i, syllable) in "아리랑".chars().enumerate() {
+            recording.record(10.5 + i as f64, note(syllable, 67, 1.0));
+        }
+        let mut bytes = Vec::new();
+        recording.to_smf().write_std(&mut bytes).unwrap();
+        let smf = Smf::parse(&bytes).unwrap();
+        assert_eq!(smf.tracks.len(), 4);
+        // 랑 ends on ㅇ: low tom, half a 박 into the file
+        let percussion = notes_of(&smf.tracks[3]);
+        assert_eq!(percussion[0], (240 + 2 * 480, 9, 45, true));
+    }
+}
@@ -0,0 +1,14 @@
+pub mod jangdan;
+pub mod live;
+pub mod midi_export;
+pub mod render;
+pub mod scale;
+pub mod sonifier;
+pub mod synth;
+
+pub use jangdan::{Jangdan, JangdanClock, Quantizer, Stroke, Tick};
+pub use live::{jamo_sounds, JamoSound};
+pub use midi_export::Recording;
+pub use scale::Scale;
+pub use sonifier::{Articulation, Articulations, Note, Registers, Sonifier};
+pub use synth::Synth;
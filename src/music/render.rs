@@ -0,0 +1,146 @@
+// src/music/render.rs
+//
+// Offline rendering: plays a `Recording` through the built-in `Synth` as
+// fast as it can and writes a WAV file. No audio device is needed, so demo
+// audio can be made anywhere, and the same recording always gives the same
+// file.
+
+use super::jangdan::Stroke;
+use super::midi_export::{session_path, Recording};
+use super::sonifier::Note;
+use super::synth::Synth;
+use std::io::{Seek, Write};
+use std::path::{Path, PathBuf};
+
+// Silence kept after the last note, for the strings to ring out
+const TAIL_SECONDS: f64 = 2.0;
+
+enum Cue<'a> {
+    Note(&'a Note),
+    Stroke(Stroke),
+}
+
+/// The recording as mono samples, from the 박 of its first note.
+pub fn render(recording: &Recording, sample_rate: u32, volume: f32) -> Vec<f32> {
+    if recording.is_empty() {
+        return Vec::new();
+    }
+    let mut synth = Synth::new(sample_rate, volume);
+    let beat_seconds = 60.0 / recording.bpm() as f64;
+    let start = recording.start();
+    let sample_at = |beats: f64| ((beats - start) * beat_seconds * sample_rate as f64) as usize;
+
+    let mut cues: Vec<(usize, Cue)> = recording
+        .notes()
+        .iter()
+        .map(|(at, note)| (sample_at(*at), Cue::Note(note)))
+        .chain(
+            recording
+                .strokes()
+                .map(|(at, stroke)| (sample_at(*at), Cue::Stroke(*stroke))),
+        )
+        .collect();
+    cues.sort_by_key(|(sample, _)| *sample);
+
+    let len = sample_at(recording.end()) + (TAIL_SECONDS * sample_rate as f64) as usize;
+    let mut samples = Vec::with_capacity(len);
+    let mut cues = cues.into_iter().peekable();
+    for i in 0..len {
+        while let Some((_, cue)) = cues.next_if(|(at, _)| *at <= i) {
+            match cue {
+                Cue::Note(note) => synth.play(note, beat_seconds as f32),
+                Cue::Stroke(stroke) => synth.strike(stroke),
+            }
+        }
+        samples.push(synth.next_sample());
+    }
+    samples
+}
+
+/// Write mono samples as 16-bit PCM.
+pub fn write_wav<W: Write + Seek>(
+    samples: &[f32],
+    sample_rate: u32,
+    writer: W,
+) -> Result<(), hound::Error> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut wav = hound::WavWriter::new(writer, spec)?;
+    for sample in samples {
+        wav.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+    }
+    wav.finalize()
+}
+
+/// Render the recording into `dir` as `autohmjeum_<unix time>.wav`.
+pub fn save_wav(
+    recording: &Recording,
+    dir: &Path,
+    sample_rate: u32,
+    volume: f32,
+) -> Result<PathBuf, hound::Error> {
+    let path = session_path(dir, "wav")?;
+    let samples = render(recording, sample_rate, volume);
+    let file = std::io::BufWriter::new(std::fs::File::create(&path)?);
+    write_wav(&samples, sample_rate, file)?;
+    Ok(path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    fn recording() -> Recording {
+        // 60 bpm: a 박 a second
+        let mut recording = Recording::new(60.0);
+        recording.record_stroke(0.0, Stroke::Hap); // before the first note
+        for (i, syllable) in "도라지".chars().enumerate() {
+            let note = Note {
+                syllable,
+                pitch: 67 + i as u8 * 2,
+                onset: 55,
+                velocity: 90,
+                beats: 1.0,
+                gate: 0.9,
+            };
+            recording.record(2.0 + i as f64, note);
+        }
+        recording.record_stroke(2.0, Stroke::Hap);
+        recording.record_stroke(2.5, Stroke::Rest);
+        recording.record_stroke(3.0, Stroke::Chae);
+        recording
+    }
+
+    #[test]
+    fn test_render() {
+        let recording = recording();
+        assert_eq!(recording.strokes().count(), 2);
+
+        // three 박 of notes and the tail
+        let samples = render(&recording, 1000, 0.5);
+        assert_eq!(samples.len(), 5000);
+        assert!(samples[..3000].iter().any(|s| s.abs() > 0.01));
+        assert!(samples[4900..].iter().all(|s| s.abs() < 1e-3));
+        assert_eq!(samples, render(&recording, 1000, 0.5));
+
+        assert!(render(&Recording::new(60.0), 1000, 0.5).is_empty());
+    }
+
+    #[test]
+    fn test_wav() {
+        let samples = render(&recording(), 8000, 0.5);
+        let mut bytes = Cursor::new(Vec::new());
+        write_wav(&samples, 8000, &mut bytes).unwrap();
+
+        bytes.set_position(0);
+        let reader = hound::WavReader::new(bytes).unwrap();
+        assert_eq!(reader.spec().sample_rate, 8000);
+        assert_eq!(reader.spec().channels, 1);
+        assert_eq!(reader.len() as usize, samples.len());
+    }
+}
@@ -0,0 +1,83 @@
+// src/music/scale.rs
+//
+// Korean modes as five-note scales, in semitones above the tonic.
+// Degrees count 궁 상 각 치 우 (0-4) and carry on into the next octave, so
+// degree 5 is 궁 an octave up and -1 is 우 an octave down.
+
+use serde::Deserialize;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Scale {
+    #[default]
+    Pyeongjo, // 평조: sol la do re mi
+    Gyemyeonjo, // 계면조: la do re mi sol
+    Menari,     // 메나리토리 (동부민요): mi sol la do re
+    Gyeong,     // 경토리 (경기민요): do re mi sol la
+}
+
+impl Scale {
+    pub const ALL: [Scale; 4] = [
+        Scale::Pyeongjo,
+        Scale::Gyemyeonjo,
+        Scale::Menari,
+        Scale::Gyeong,
+    ];
+
+    pub fn intervals(self) -> [i32; 5] {
+        match self {
+            Scale::Pyeongjo => [0, 2, 5, 7, 9],
+            Scale::Gyemyeonjo => [0, 3, 5, 7, 10],
+            Scale::Menari => [0, 3, 5, 8, 10],
+            Scale::Gyeong => [0, 2, 4, 7, 9],
+        }
+    }
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Scale::Pyeongjo => "평조",
+            Scale::Gyemyeonjo => "계면조",
+            Scale::Menari => "메나리토리",
+            Scale::Gyeong => "경토리",
+        }
+    }
+
+    /// The next scale in `ALL`, wrapping round.
+    pub fn next(self) -> Self {
+        let at = Self::ALL.iter().position(|s| *s == self).unwrap_or(0);
+        Self::ALL[(at + 1) % Self::ALL.len()]
+    }
+
+    /// Semitones above the tonic for a degree, any octave.
+    pub fn semitones(self, degree: i32) -> i32 {
+        let steps = self.intervals();
+        let octave = degree.div_euclid(steps.len() as i32);
+        let step = degree.rem_euclid(steps.len() as i32) as usize;
+        octave * 12 + steps[step]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_degrees_across_octaves() {
+        let scale = Scale::Pyeongjo;
+        assert_eq!(scale.semitones(0), 0);
+        assert_eq!(scale.semitones(4), 9);
+        assert_eq!(scale.semitones(5), 12);
+        assert_eq!(scale.semitones(-1), -3);
+        assert_eq!(Scale::Gyemyeonjo.semitones(6), 15);
+    }
+
+    #[test]
+    fn test_cycle() {
+        let mut scale = Scale::default();
+        for _ in 0..Scale::ALL.len() {
+            scale = scale.next();
+        }
+        assert_eq!(scale, Scale::Pyeongjo);
+        assert_eq!(Scale::Pyeongjo.next(), Scale::Gyemyeonjo);
+    }
+}
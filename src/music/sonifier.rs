@@ -0,0 +1,205 @@
+// src/music/sonifier.rs
+//
+// Turns committed syllables into notes:
+//  - the choseong picks the scale degree, from a table in config.toml
+//  - the jungseong picks the register: yang vowels up, yin vowels down
+//  - the jongseong sets how the note is played, by what it leaves ringing
+//    (open, nasal, liquid, stopped)
+// The same syllable always gives the same note.
+
+use super::scale::Scale;
+use crate::services::jamo::{decompose_conjoining, to_compat};
+use crate::services::syllable_analysis::{CodaClass, Harmony, SyllableFeatures};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+/// Octave shift for each vowel harmony.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Registers {
+    pub yang: i32,
+    pub yin: i32,
+    pub neutral: i32,
+}
+
+/// Length in beats, the part of it that sounds, and MIDI velocity.
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+pub struct Articulation {
+    pub beats: f32,
+    pub gate: f32,
+    pub velocity: u8,
+}
+
+/// Articulation for each kind of ending.
+#[derive(Debug, Clone, Copy, Deserialize)]
+pub struct Articulations {
+    pub open: Articulation,
+    pub nasal: Articulation,
+    pub liquid: Articulation,
+    pub stop: Articulation,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Note {
+    pub syllable: char,
+    pub pitch: u8, // MIDI note number
+    pub onset: u8, // the initial's own pitch, before the vowel moves it
+    pub velocity: u8,
+    pub beats: f32,
+    pub gate: f32, // fraction of `beats` the note sounds for
+}
+
+#[derive(Debug, Clone)]
+pub struct Sonifier {
+    pub scale: Scale,
+    tonic: i32,
+    degrees: HashMap<char, i32>,
+    registers: Registers,
+    articulations: Articulations,
+}
+
+impl Sonifier {
+    /// `degrees` maps each choseong (as a one-letter string, the way it is
+    /// written in config.toml) to a scale degree. Initials without a degree
+    /// stay silent.
+    pub fn new(
+        scale: Scale,
+        tonic: u8,
+        degrees: &HashMap<String, i32>,
+        registers: Registers,
+        articulations: Articulations,
+    ) -> Self {
+        let degrees = degrees
+            .iter()
+            .filter_map(|(letter, degree)| {
+                let mut chars = letter.chars();
+                match (chars.next(), chars.next()) {
+                    (Some(letter), None) => Some((letter, *degree)),
+                    _ => {
+                        println!("Music: ignoring choseong {:?}, not one letter", letter);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self {
+            scale,
+            tonic: tonic as i32,
+            degrees,
+            registers,
+            articulations,
+        }
+    }
+
+    /// The note for one syllable, if its initial has a degree.
+    pub fn note_for(&self, syllable: &SyllableFeatures) -> Option<Note> {
+        let (l, _, _) = decompose_conjoining(syllable.syllable)?;
+        let degree = *self.degrees.get(&to_compat(l)?)?;
+
+        let register = match syllable.harmony {
+            Harmony::Yang => self.registers.yang,
+            Harmony::Yin => self.registers.yin,
+            Harmony::Neutral => self.registers.neutral,
+        };
+        let onset = self.tonic + self.scale.semitones(degree);
+        let pitch = onset + register * 12;
+
+        let articulation = match syllable.coda {
+            CodaClass::Open => self.articulations.open,
+            CodaClass::Nasal => self.articulations.nasal,
+            CodaClass::Liquid => self.articulations.liquid,
+            CodaClass::Stop => self.articulations.stop,
+        };
+
+        Some(Note {
+            syllable: syllable.syllable,
+            pitch: pitch.clamp(0, 127) as u8,
+            onset: onset.clamp(0, 127) as u8,
+            velocity: articulation.velocity.min(127),
+            beats: articulation.beats,
+            gate: articulation.gate.clamp(0.0, 1.0),
+        })
+    }
+
+    /// The pitch of a consonant on its own, in the tonic's octave; `None`
+    /// for letters without a degree.
+    pub fn consonant_pitch(&self, jamo: char) -> Option<u8> {
+        let letter = to_compat(jamo).unwrap_or(jamo);
+        let degree = *self.degrees.get(&letter)?;
+        Some((self.tonic + self.scale.semitones(degree)).clamp(0, 127) as u8)
+    }
+
+    pub fn notes(&self, syllables: &[SyllableFeatures]) -> Vec<Note> {
+        syllables.iter().filter_map(|s| self.note_for(s)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::syllable_analysis::analyze;
+
+    fn sonifier() -> Sonifier {
+        // 순음 궁, 치음 상, 아음 각, 설음 치, 후음 우
+        let degrees: HashMap<String, i32> = [("ㅁ", 0), ("ㅅ", 1), ("ㄱ", 2), ("ㄴ", 3), ("ㅇ", 4)]
+            .iter()
+            .map(|(letter, degree)| (letter.to_string(), *degree))
+            .collect();
+        let articulation = |beats, gate, velocity| Articulation {
+            beats,
+            gate,
+            velocity,
+        };
+        Sonifier::new(
+            Scale::Pyeongjo,
+            60,
+            &degrees,
+            Registers {
+                yang: 1,
+                yin: -1,
+                neutral: 0,
+            },
+            Articulations {
+                open: articulation(1.0, 0.9, 80),
+                nasal: articulation(1.0, 1.0, 70),
+                liquid: articulation(0.75, 1.0, 70),
+                stop: articulation(0.5, 0.4, 100),
+            },
+        )
+    }
+
+    #[test]
+    fn test_initial_picks_degree() {
+        let sonifier = sonifier();
+        let pitches: Vec<u8> = sonifier
+            .notes(&analyze("미 시 기 니 이"))
+            .iter()
+            .map(|note| note.pitch)
+            .collect();
+        // ㅣ is neutral: the tonic's own octave
+        assert_eq!(pitches, vec![60, 62, 65, 67, 69]);
+    }
+
+    #[test]
+    fn test_vowel_picks_register() {
+        let sonifier = sonifier();
+        let notes = sonifier.notes(&analyze("마 머"));
+        assert_eq!(notes[0].pitch, 72);
+        assert_eq!(notes[1].pitch, 48);
+        assert_eq!(notes[0].onset, 60);
+        assert_eq!(notes[1].onset, 60);
+        assert_eq!(sonifier.consonant_pitch('ㅁ'), Some(60));
+        assert_eq!(sonifier.consonant_pitch('\u{1109}'), Some(62)); // conjoining ㅅ
+        assert_eq!(sonifier.consonant_pitch('ㅎ'), None);
+    }
+
+    #[test]
+    fn test_final_picks_articulation() {
+        let sonifier = sonifier();
+        let notes = sonifier.notes(&analyze("가 강 갈 각"));
+        let beats: Vec<f32> = notes.iter().map(|note| note.beats).collect();
+        assert_eq!(beats, vec![1.0, 1.0, 0.75, 0.5]);
+        assert_eq!(notes[3].velocity, 100);
+        // no degree for ㅎ
+        assert!(sonifier.notes(&analyze("하")).is_empty());
+    }
+}
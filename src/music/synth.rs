@@ -0,0 +1,401 @@
+// src/music/synth.rs
+//
+// A small built-in synthesizer, so the piece can sound without a DAW or an
+// external sound engine:
+//  - notes on a plucked string (Karplus-Strong), close to a 가야금's silk
+//    strings: bright on the attack, high notes dying faster than low ones
+//  - typed consonants as a short tap on the same string
+//  - typed vowels as a held, reedy tone with 농현 (a vibrato that deepens
+//    as the note is held)
+//  - jangdan strokes on a 장구: 북편 a low membrane that drops in pitch,
+//    채편 a stick crack over a higher head, 합장단 both at once
+// Mono, one sample at a time. The noise is seeded, so the same notes always
+// give the same samples.
+
+use super::jangdan::Stroke;
+use super::live::JamoSound;
+use super::sonifier::Note;
+
+// Most voices sounding at once; the oldest is cut past this.
+const MAX_VOICES: usize = 32;
+// Loss of the string per pass round the delay line
+const STRING_DECAY: f32 = 0.996;
+// Fade after the gate closes, in seconds
+const RELEASE: f32 = 0.08;
+// How long a consonant tap sounds, in seconds
+const TAP_LENGTH: f32 = 0.05;
+// Fade in and out of a held vowel, in seconds
+const TONE_ATTACK: f32 = 0.03;
+const TONE_RELEASE: f32 = 0.15;
+// Length of a drum stroke, in seconds
+const DRUM_LENGTH: f32 = 1.2;
+const TAU: f32 = std::f32::consts::TAU;
+
+/// xorshift noise; cheap enough for the audio thread and the same on every
+/// platform.
+#[derive(Debug, Clone)]
+struct Noise(u32);
+
+impl Noise {
+    fn next(&mut self) -> f32 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 17;
+        self.0 ^= self.0 << 5;
+        self.0 as f32 / u32::MAX as f32 * 2.0 - 1.0
+    }
+}
+
+fn frequency(pitch: u8) -> f32 {
+    440.0 * 2f32.powf((pitch as f32 - 69.0) / 12.0)
+}
+
+#[derive(Debug, Clone)]
+struct Pluck {
+    line: Vec<f32>,
+    pos: usize,
+    gain: f32,
+    age: usize,
+    release_at: usize, // samples until the gate closes
+    release_len: usize,
+}
+
+impl Pluck {
+    // `seconds` until the gate closes
+    fn new(pitch: u8, velocity: u8, seconds: f32, sample_rate: f32, noise: &mut Noise) -> Self {
+        let len = (sample_rate / frequency(pitch)).round().max(2.0) as usize;
+        let mut line: Vec<f32> = (0..len).map(|_| noise.next()).collect();
+        // softer notes are plucked less brightly: smooth the burst
+        let smoothing = 1.0 - velocity as f32 / 127.0;
+        for i in 1..len {
+            line[i] = line[i] * (1.0 - smoothing) + line[i - 1] * smoothing;
+        }
+        Self {
+            line,
+            pos: 0,
+            gain: velocity as f32 / 127.0,
+            age: 0,
+            release_at: (seconds * sample_rate) as usize,
+            release_len: (RELEASE * sample_rate) as usize,
+        }
+    }
+
+    fn next(&mut self) -> f32 {
+        let next = (self.pos + 1) % self.line.len();
+        let out = self.line[self.pos];
+        self.line[self.pos] = STRING_DECAY * 0.5 * (out + self.line[next]);
+        self.pos = next;
+
+        let released = self.age.saturating_sub(self.release_at);
+        let envelope = 1.0 - released as f32 / self.release_len.max(1) as f32;
+        self.age += 1;
+        out * self.gain * envelope.max(0.0)
+    }
+
+    fn is_done(&self) -> bool {
+        self.age >= self.release_at + self.release_len
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Tone {
+    hz: f32,
+    gain: f32,
+    age: usize,
+    sample_rate: f32,
+    release_at: usize,
+    phase: f32,
+    vibrato_phase: f32,
+}
+
+impl Tone {
+    fn new(note: &Note, seconds: f32, sample_rate: f32) -> Self {
+        Self {
+            hz: frequency(note.pitch),
+            gain: note.velocity as f32 / 127.0,
+            age: 0,
+            sample_rate,
+            release_at: (seconds * sample_rate) as usize,
+            phase: 0.0,
+            vibrato_phase: 0.0,
+        }
+    }
+
+    fn next(&mut self) -> f32 {
+        let t = self.age as f32 / self.sample_rate;
+        let released = self.age.saturating_sub(self.release_at) as f32 / self.sample_rate;
+        self.age += 1;
+
+        // 농현: 5 Hz, deepening to about a quarter tone over half a second
+        self.vibrato_phase = (self.vibrato_phase + 5.0 / self.sample_rate).fract();
+        let depth = 0.015 * (t / 0.5).min(1.0);
+        let hz = self.hz * (1.0 + depth * (self.vibrato_phase * TAU).sin());
+        self.phase = (self.phase + hz / self.sample_rate).fract();
+
+        let p = self.phase * TAU;
+        let wave = (p.sin() + 0.3 * (2.0 * p).sin() + 0.1 * (3.0 * p).sin()) / 1.4;
+        let envelope = (t / TONE_ATTACK).min(1.0) * (1.0 - released / TONE_RELEASE).max(0.0);
+        wave * self.gain * envelope
+    }
+
+    fn is_done(&self) -> bool {
+        self.age as f32 >= self.release_at as f32 + TONE_RELEASE * self.sample_rate
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Drum {
+    buk: bool,
+    chae: bool,
+    gain: f32,
+    age: usize,
+    sample_rate: f32,
+    buk_phase: f32,
+    chae_phase: f32,
+}
+
+impl Drum {
+    fn new(stroke: Stroke, sample_rate: f32) -> Self {
+        Self {
+            buk: matches!(stroke, Stroke::Hap | Stroke::Buk),
+            chae: matches!(stroke, Stroke::Hap | Stroke::Chae),
+            gain: stroke.accent(),
+            age: 0,
+            sample_rate,
+            buk_phase: 0.0,
+            chae_phase: 0.0,
+        }
+    }
+
+    fn next(&mut self, noise: &mut Noise) -> f32 {
+        let t = self.age as f32 / self.sample_rate;
+        self.age += 1;
+        let mut out = 0.0;
+        if self.buk {
+            // the skin slackens after the hit: 110 Hz down to 60
+            let hz = 60.0 + 50.0 * (-t / 0.04).exp();
+            self.buk_phase = (self.buk_phase + hz / self.sample_rate).fract();
+            out += (self.buk_phase * TAU).sin() * (-t / 0.35).exp();
+        }
+        if self.chae {
+            self.chae_phase = (self.chae_phase + 260.0 / self.sample_rate).fract();
+            out += 0.5 * (self.chae_phase * TAU).sin() * (-t / 0.12).exp();
+            out += 0.5 * noise.next() * (-t / 0.015).exp();
+        }
+        out * self.gain
+    }
+
+    fn is_done(&self) -> bool {
+        self.age as f32 >= DRUM_LENGTH * self.sample_rate
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Voice {
+    Pluck(Pluck),
+    Tone(Tone),
+    Drum(Drum),
+}
+
+#[derive(Debug, Clone)]
+pub struct Synth {
+    sample_rate: f32,
+    volume: f32,
+    voices: Vec<Voice>,
+    noise: Noise,
+}
+
+impl Synth {
+    pub fn new(sample_rate: u32, volume: f32) -> Self {
+        Self {
+            sample_rate: sample_rate.max(1) as f32,
+            volume,
+            voices: Vec::new(),
+            noise: Noise(0x2545_F491),
+        }
+    }
+
+    pub fn sample_rate(&self) -> u32 {
+        self.sample_rate as u32
+    }
+
+    /// For an audio device that runs at another rate than asked for.
+    /// Voices already sounding keep the old rate.
+    pub fn set_sample_rate(&mut self, sample_rate: u32) {
+        self.sample_rate = sample_rate.max(1) as f32;
+    }
+
+    pub fn set_volume(&mut self, volume: f32) {
+        self.volume = volume;
+    }
+
+    /// Pluck `note`; `beat_seconds` is the length of a 박 at the current
+    /// tempo.
+    pub fn play(&mut self, note: &Note, beat_seconds: f32) {
+        let seconds = note.beats * note.gate * beat_seconds;
+        let pluck = Pluck::new(
+            note.pitch,
+            note.velocity,
+            seconds,
+            self.sample_rate,
+            &mut self.noise,
+        );
+        self.add(Voice::Pluck(pluck));
+    }
+
+    /// Sound a jamo as it is typed: consonants tap, vowels are held for
+    /// the length of their syllable's note.
+    pub fn play_jamo(&mut self, sound: &JamoSound, beat_seconds: f32) {
+        match sound {
+            JamoSound::Consonant { pitch, velocity } => {
+                let tap = Pluck::new(
+                    *pitch,
+                    *velocity,
+                    TAP_LENGTH,
+                    self.sample_rate,
+                    &mut self.noise,
+                );
+                self.add(Voice::Pluck(tap));
+            }
+            JamoSound::Vowel(note) => {
+                let seconds = note.beats * note.gate * beat_seconds;
+                self.add(Voice::Tone(Tone::new(note, seconds, self.sample_rate)));
+            }
+        }
+    }
+
+    /// Hit the 장구. Rests are silent.
+    pub fn strike(&mut self, stroke: Stroke) {
+        if stroke != Stroke::Rest {
+            self.add(Voice::Drum(Drum::new(stroke, self.sample_rate)));
+        }
+    }
+
+    fn add(&mut self, voice: Voice) {
+        if self.voices.len() >= MAX_VOICES {
+            self.voices.remove(0);
+        }
+        self.voices.push(voice);
+    }
+
+    pub fn next_sample(&mut self) -> f32 {
+        let noise = &mut self.noise;
+        let mut mix = 0.0;
+        for voice in &mut self.voices {
+            mix += match voice {
+                Voice::Pluck(pluck) => pluck.next(),
+                Voice::Tone(tone) => tone.next(),
+                Voice::Drum(drum) => drum.next(noise),
+            };
+        }
+        self.voices.retain(|voice| match voice {
+            Voice::Pluck(pluck) => !pluck.is_done(),
+            Voice::Tone(tone) => !tone.is_done(),
+            Voice::Drum(drum) => !drum.is_done(),
+        });
+        // soft clip, so chords and strokes together don't wrap
+        (mix * self.volume).tanh()
+    }
+
+    pub fn fill(&mut self, out: &mut [f32]) {
+        for sample in out {
+            *sample = self.next_sample();
+        }
+    }
+
+    /// Nothing is sounding.
+    pub fn is_silent(&self) -> bool {
+        self.voices.is_empty()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn note(pitch: u8, gate: f32) -> Note {
+        Note {
+            syllable: '가',
+            pitch,
+            onset: pitch,
+            velocity: 100,
+            beats: 1.0,
+            gate,
+        }
+    }
+
+    #[test]
+    fn test_pluck_is_in_tune() {
+        // A4 is 100 samples a cycle at 44 kHz
+        let mut synth = Synth::new(44_000, 0.1);
+        synth.play(&note(69, 1.0), 1.0);
+        let mut samples = vec![0.0; 6000];
+        synth.fill(&mut samples);
+
+        // the wave repeats best after one period
+        let correlation =
+            |lag: usize| -> f32 { (2000..5000).map(|i| samples[i] * samples[i + lag]).sum() };
+        let period = (80..120)
+            .max_by(|a, b| correlation(*a).total_cmp(&correlation(*b)))
+            .unwrap();
+        assert!((99..=101).contains(&period), "{}", period);
+    }
+
+    #[test]
+    fn test_voices_end() {
+        let mut synth = Synth::new(1000, 1.0);
+        synth.play(&note(60, 0.5), 1.0);
+        synth.strike(Stroke::Hap);
+        synth.strike(Stroke::Rest);
+        assert!(!synth.is_silent());
+
+        // the pluck is gated after half a second and released after 0.08
+        let mut samples = vec![0.0; 600];
+        synth.fill(&mut samples);
+        assert_eq!(synth.voices.len(), 1);
+        // the drum rings for its whole length
+        let mut samples = vec![0.0; 700];
+        synth.fill(&mut samples);
+        assert!(synth.is_silent());
+        assert_eq!(synth.next_sample(), 0.0);
+    }
+
+    #[test]
+    fn test_jamo_timbres() {
+        let mut synth = Synth::new(1000, 1.0);
+        synth.play_jamo(
+            &JamoSound::Consonant {
+                pitch: 60,
+                velocity: 100,
+            },
+            1.0,
+        );
+        synth.play_jamo(&JamoSound::Vowel(note(72, 1.0)), 1.0);
+        // the vowel fades in
+        let first = synth.next_sample();
+        let mut samples = vec![0.0; 199];
+        synth.fill(&mut samples);
+        assert!(first.abs() < samples[50..].iter().fold(0.0f32, |m, s| m.max(s.abs())));
+
+        // the consonant is gone after its tap, the vowel is still held
+        assert_eq!(synth.voices.len(), 1);
+        assert!(matches!(synth.voices[0], Voice::Tone(_)));
+        let mut samples = vec![0.0; 1000];
+        synth.fill(&mut samples);
+        assert!(synth.is_silent());
+    }
+
+    #[test]
+    fn test_same_notes_same_samples() {
+        let render = || {
+            let mut synth = Synth::new(8000, 0.5);
+            synth.play(&note(62, 1.0), 0.5);
+            synth.strike(Stroke::Chae);
+            let mut samples = vec![0.0; 4000];
+            synth.fill(&mut samples);
+            samples
+        };
+        let samples = render();
+        assert_eq!(samples, render());
+        assert!(samples.iter().all(|s| s.abs() < 1.0));
+    }
+}
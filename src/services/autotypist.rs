@@ -0,0 +1,243 @@
+// src/services/autotypist.rs
+//
+// Autopilot: performs a text as if someone were typing it.
+// Each syllable is spelled out as the dubeolsik keystrokes a person would
+// press (괅 → ㄱ ㅗ ㅏ ㄹ ㄱ), so the composer goes through the same preedit
+// states as live input. Timing follows a words-per-minute pace with random
+// jitter, lingers at punctuation and line ends, and now and then hits a
+// neighbouring key and backspaces over it.
+
+use super::hangeul_composer::{is_punctuation, syllable_keystrokes};
+use super::keyboard_layout::dubeolsik_jamo;
+use rand::Rng;
+use std::collections::VecDeque;
+
+// Standard typing-test word length, in keystrokes.
+const KEYS_PER_WORD: f32 = 5.0;
+
+// QWERTY rows, for finding the key next to the intended one.
+const KEY_ROWS: [&str; 3] = ["qwertyuiop", "asdfghjkl", "zxcvbnm"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Keystroke {
+    Char(char),
+    Backspace,
+    Enter,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct TypingStyle {
+    pub wpm: f32,
+    pub jitter: f32,            // ± fraction of the mean delay
+    pub punctuation_pause: f32, // extra seconds after punctuation
+    pub line_pause: f32,        // extra seconds after a line is submitted
+    pub typo_rate: f32,         // chance per jamo of a wrong key first
+}
+
+// Keystrokes still to come, each with the delay before it.
+#[derive(Debug, Clone)]
+pub struct AutoTypist {
+    queue: VecDeque<(f32, Keystroke)>,
+    wait: f32,
+}
+
+impl AutoTypist {
+    pub fn new(text: &str, style: &TypingStyle, rng: &mut impl Rng) -> Self {
+        let queue = schedule(&keystrokes_for(text), style, rng);
+        let wait = queue.front().map_or(0.0, |(delay, _)| *delay);
+        Self { queue, wait }
+    }
+
+    /// Hold the first keystroke back by `seconds` more.
+    pub fn delayed(mut self, seconds: f32) -> Self {
+        self.wait += seconds;
+        self
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.queue.is_empty()
+    }
+
+    /// Advance the clock by `dt` seconds and return the keystrokes now due.
+    pub fn update(&mut self, dt: f32) -> Vec<Keystroke> {
+        let mut due = Vec::new();
+        self.wait -= dt;
+        while self.wait <= 0.0 {
+            let Some((_, key)) = self.queue.pop_front() else {
+                break;
+            };
+            due.push(key);
+            match self.queue.front() {
+                Some((delay, _)) => self.wait += delay,
+                None => break,
+            }
+        }
+        due
+    }
+}
+
+/// The keys a dubeolsik typist presses for `text`.
+pub fn keystrokes_for(text: &str) -> Vec<Keystroke> {
+    let mut keys = Vec::new();
+    for line in text.lines() {
+        for ch in line.chars() {
+            let jamo = syllable_keystrokes(ch);
+            if jamo.is_empty() {
+                keys.push(Keystroke::Char(ch));
+            } else {
+                keys.extend(jamo.into_iter().map(Keystroke::Char));
+            }
+        }
+        keys.push(Keystroke::Enter);
+    }
+    keys
+}
+
+/// Give every keystroke its delay, slipping in typos on the way.
+fn schedule(
+    keys: &[Keystroke],
+    style: &TypingStyle,
+    rng: &mut impl Rng,
+) -> VecDeque<(f32, Keystroke)> {
+    let mean = 60.0 / (style.wpm.max(1.0) * KEYS_PER_WORD);
+    let jitter = style.jitter.clamp(0.0, 1.0);
+    let delay = |rng: &mut _| mean * (1.0 + Rng::gen_range(rng, -jitter..=jitter));
+
+    let mut queue = VecDeque::new();
+    let mut pause = 0.0;
+    for &key in keys {
+        if let Keystroke::Char(ch) = key {
+            if rng.gen::<f32>() < style.typo_rate {
+                if let Some(wrong) = neighbouring_jamo(ch, rng) {
+                    queue.push_back((pause + delay(rng), Keystroke::Char(wrong)));
+                    // noticing the slip takes a moment
+                    queue.push_back((delay(rng) * 3.0, Keystroke::Backspace));
+                    pause = 0.0;
+                }
+            }
+        }
+        queue.push_back((pause + delay(rng), key));
+
+        pause = match key {
+            Keystroke::Char(ch) if is_punctuation(&ch) && ch != ' ' => style.punctuation_pause,
+            Keystroke::Enter => style.line_pause,
+            _ => 0.0,
+        };
+    }
+    queue
+}
+
+/// The jamo on a key next to the one that types `jamo`, if any.
+fn neighbouring_jamo(jamo: char, rng: &mut impl Rng) -> Option<char> {
+    let key = ('a'..='z').find(|&k| dubeolsik_jamo(k) == Some(jamo))?;
+    let row: Vec<char> = KEY_ROWS
+        .iter()
+        .find(|row| row.contains(key))?
+        .chars()
+        .collect();
+    let at = row.iter().position(|&k| k == key)?;
+
+    let neighbours: Vec<char> = [at.checked_sub(1), Some(at + 1)]
+        .into_iter()
+        .flatten()
+        .filter_map(|i| row.get(i).copied())
+        .collect();
+    let neighbour = neighbours[rng.gen_range(0..neighbours.len())];
+    dubeolsik_jamo(neighbour)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::hangeul_composer::HangeulComposer;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn style(typo_rate: f32) -> TypingStyle {
+        TypingStyle {
+            wpm: 60.0,
+            jitter: 0.3,
+            punctuation_pause: 0.5,
+            line_pause: 1.0,
+            typo_rate,
+        }
+    }
+
+    // Play keystrokes into a composer the way the app does.
+    fn perform(keys: &[Keystroke]) -> Vec<String> {
+        let mut composer = HangeulComposer::new();
+        let mut lines = Vec::new();
+        for key in keys {
+            match key {
+                Keystroke::Char(ch) => composer.push_char(*ch),
+                Keystroke::Backspace => composer.backspace(),
+                Keystroke::Enter => lines.push(composer.submit()),
+            }
+        }
+        lines
+    }
+
+    #[test]
+    fn test_keystrokes_split_compounds() {
+        use Keystroke::*;
+        assert_eq!(
+            keystrokes_for("괅!"),
+            vec![
+                Char('ㄱ'),
+                Char('ㅗ'),
+                Char('ㅏ'),
+                Char('ㄹ'),
+                Char('ㄱ'),
+                Char('!'),
+                Enter
+            ]
+        );
+    }
+
+    #[test]
+    fn test_performance_reproduces_text() {
+        let poem = "동창이 밝았느냐 노고지리 우지진다\n소 치는 아희놈은 상기 아니 일었느냐";
+        let expected: Vec<&str> = poem.lines().collect();
+
+        assert_eq!(perform(&keystrokes_for(poem)), expected);
+
+        // typos are always taken back
+        let mut rng = StdRng::seed_from_u64(7);
+        let typist = AutoTypist::new(poem, &style(0.3), &mut rng);
+        let keys: Vec<Keystroke> = typist.queue.iter().map(|(_, key)| *key).collect();
+        assert!(keys.contains(&Keystroke::Backspace));
+        assert_eq!(perform(&keys), expected);
+    }
+
+    #[test]
+    fn test_timing() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut typist = AutoTypist::new("가.", &style(0.0), &mut rng);
+
+        let step = 0.01;
+        let mut times = Vec::new();
+        let mut keys = Vec::new();
+        for frame in 1..=500 {
+            for key in typist.update(step) {
+                times.push(frame as f32 * step);
+                keys.push(key);
+            }
+        }
+        assert!(typist.is_finished());
+        assert_eq!(
+            keys,
+            vec![
+                Keystroke::Char('ㄱ'),
+                Keystroke::Char('ㅏ'),
+                Keystroke::Char('.'),
+                Keystroke::Enter
+            ]
+        );
+
+        // 60 wpm is 0.2 s per key, and jitter keeps it within 30%
+        for gap in times.windows(2).take(2).map(|w| w[1] - w[0]) {
+            assert!((0.13..=0.27).contains(&gap), "gap {}", gap);
+        }
+        // the full stop holds the line back
+        assert!(times[3] - times[2] >= 0.5 + 0.13);
+    }
+}
@@ -0,0 +1,31 @@
+// src/services/composer_event.rs
+//
+// Structured events emitted by the HangeulComposer.
+// The composer queues them as it works; the render loop drains the queue
+// once per frame and hands the events to whatever reacts to typing
+// (visuals, sound, network outputs).
+
+use super::jamo::JamoRole;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum ComposerEvent {
+    /// A jamo keystroke, with the role it took when it was typed.
+    /// A later vowel may still move a final over (see SyllableResyllabified).
+    JamoTyped {
+        jamo: char,
+        role: JamoRole,
+    },
+    /// The preedit became a new complete syllable (ㄱ+ㅏ → 가, 가+ㄴ → 간).
+    SyllableFormed(String),
+    /// A vowel took the final of the syllable in front of it:
+    /// 닭 + ㅏ → 달 / 가 is `{ from: '닭', to: ('달', '가') }`.
+    SyllableResyllabified {
+        from: char,
+        to: (char, char),
+    },
+    /// Text left the preedit and can no longer change.
+    SyllableCommitted(String),
+    /// Enter was pressed; the whole line.
+    LineSubmitted(String),
+    Backspaced,
+}
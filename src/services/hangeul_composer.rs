@@ -1,55 +1,1383 @@
-// src/services/hangeul_parser.rs
+// src/services/hangeul_composer.rs
+//
+// Jamo composition state machine for the input line.
+// Owns the committed text and the composing (preedit) buffer so that
+// the input window, the main output and the tests all drive the same engine.
+// Composition happens at the cursor: `committed` is the text before it and
+// `after` the text behind it, with the preedit shown in between.
+//
+// Two kinds of input are understood:
+//  - compatibility jamo (dubeolsik), where syllable boundaries are guessed
+//    and a vowel may pull a final over into the next syllable;
+//  - conjoining jamo (sebeolsik), where every jamo carries its role and
+//    boundaries are unambiguous, so no re-syllabification happens.
+// In Old Hangeul mode the composing buffer is handed to `old_hangeul`
+// instead, which knows the archaic letters and clusters.
+// Every change is also queued as a ComposerEvent for the render loop.
 
+use super::composer_event::ComposerEvent;
+use super::jamo::{conjoining_role, is_syllable, to_compat, JamoRole};
+use super::normalization::canonicalize;
+use super::old_hangeul::{is_old_letter, is_vowel_letter, split_syllables};
+use super::romaja::{is_boundary_mark, romaja_to_hangeul};
 use hangeul::*;
-use rustkorean::*;
 
-#[derive(Default, Debug)]
-pub struct Karacter {
-    first: Option<char>,     // current character in the stream
-    second: Option<char>,    // next character in the stream
-    third: Option<char>,     // the one after the next
-    choseong: Option<char>,  // a possible choseong (initial)
-    jungseong: Option<char>, // a possible jungseong (medial)
-    jongseong: Option<char>, // a possible jongseong (final)
+// Longest raw jamo run we keep composing before forcing the oldest out.
+const MAX_COMPOSING: usize = 5;
+
+// Keystrokes we can undo exactly; older ones fall back to decomposition.
+const MAX_UNDO: usize = 1024;
+
+// Undrained events kept; the oldest go first if nobody is listening.
+const MAX_EVENTS: usize = 1024;
+
+// Editable state, saved before every keystroke so that backspace is an
+// exact inverse of typing.
+#[derive(Default, Debug, Clone, PartialEq)]
+struct Snapshot {
+    committed: String,
+    after: String,
+    composing: Vec<char>,
+    romaja: String,
 }
 
-impl Karacter {
+#[derive(Default, Debug, Clone)]
+pub struct HangeulComposer {
+    committed: String,    // text that can no longer change, up to the cursor
+    after: String,        // committed text after the cursor
+    composing: Vec<char>, // raw jamo still being assembled
+    romaja: String,       // romanized word still being typed
+    undo: Vec<Snapshot>,  // one entry per keystroke, newest last
+    old_hangeul: bool,    // compose archaic letters into conjoining jamo
+    events: Vec<ComposerEvent>,
+}
+
+impl HangeulComposer {
     pub fn new() -> Self {
         Self {
-            first: None,
-            second: None,
-            third: None,
-            choseong: None,
-            jungseong: None,
-            jongseong: None,
+            committed: String::new(),
+            after: String::new(),
+            composing: Vec::new(),
+            romaja: String::new(),
+            undo: Vec::new(),
+            old_hangeul: false,
+            events: Vec::new(),
+        }
+    }
+
+    /************************* Accessors ********************/
+
+    /// The committed line, on both sides of the cursor.
+    pub fn committed(&self) -> String {
+        let mut committed = self.committed.clone();
+        committed.push_str(&self.after);
+        committed
+    }
+
+    /// The committed text in front of the cursor.
+    pub fn before_cursor(&self) -> &str {
+        &self.committed
+    }
+
+    /// The composing buffer as it should be shown: one syllable if the
+    /// whole buffer forms one, otherwise the clustered jamo.
+    pub fn preedit(&self) -> String {
+        if !self.romaja.is_empty() {
+            return romaja_to_hangeul(&self.romaja);
+        }
+
+        if self.old_hangeul {
+            return split_syllables(&self.composing)
+                .iter()
+                .map(|syllable| syllable.render())
+                .collect();
+        }
+
+        if self.composing_is_positional() {
+            return parse_positional(&self.composing)
+                .map(|syllable| syllable.to_string())
+                .unwrap_or_default();
+        }
+
+        let (clusters, _) = cluster_jamo_with_spans(&self.composing);
+
+        if let Some(one) = collapse_to_syllable(&clusters) {
+            one.to_string()
+        } else {
+            clusters.into_iter().collect()
+        }
+    }
+
+    /// The line as shown, with the preedit at the cursor.
+    pub fn display(&self) -> String {
+        let mut display = self.committed.clone();
+        display.push_str(&self.preedit());
+        display.push_str(&self.after);
+        display
+    }
+
+    /// Cursor position in `display()`, in chars, just behind the preedit.
+    pub fn cursor(&self) -> usize {
+        self.committed.chars().count() + self.preedit().chars().count()
+    }
+
+    /// The word the cursor is in: committed text back to the last space,
+    /// plus the preedit.
+    pub fn current_word(&self) -> String {
+        let mut word: Vec<char> = self
+            .committed
+            .chars()
+            .rev()
+            .take_while(|c| !c.is_whitespace())
+            .collect();
+        word.reverse();
+        word.extend(self.preedit().chars());
+        word.into_iter().collect()
+    }
+
+    pub fn composing(&self) -> &[char] {
+        &self.composing
+    }
+
+    pub fn romaja(&self) -> &str {
+        &self.romaja
+    }
+
+    pub fn is_old_hangeul(&self) -> bool {
+        self.old_hangeul
+    }
+
+    /// Switch the Old Hangeul (옛한글) mode. Whatever is composing is
+    /// committed first, and undo history doesn't cross the switch.
+    pub fn set_old_hangeul(&mut self, on: bool) {
+        if on != self.old_hangeul {
+            self.commit();
+            self.undo.clear();
+            self.old_hangeul = on;
+        }
+    }
+
+    /// Take the events queued since the last call, oldest first.
+    pub fn drain_events(&mut self) -> Vec<ComposerEvent> {
+        std::mem::take(&mut self.events)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.committed.is_empty()
+            && self.after.is_empty()
+            && self.composing.is_empty()
+            && self.romaja.is_empty()
+    }
+
+    /************************* Editing ********************/
+
+    /// Feed one typed character into the engine.
+    pub fn push_char(&mut self, ch: char) {
+        // Return, backspace etc. also arrive as characters; keys handle those
+        if ch.is_control() {
+            return;
+        }
+
+        self.save_snapshot();
+        let before = self.preedit();
+        let first_event = self.events.len();
+
+        self.apply_char(ch);
+
+        if let Some(role) = self.typed_role(ch) {
+            let typed = ComposerEvent::JamoTyped { jamo: ch, role };
+            self.events.insert(first_event, typed);
+        }
+        let after = self.preedit();
+        if after != before && self.preedit_is_syllable() {
+            self.emit(ComposerEvent::SyllableFormed(after));
         }
+        self.trim_events();
     }
 
-    pub fn process_char(&mut self, c: char) -> Option<char> {
-        // First, fill this and next.
-        if self.first.is_none() {
-            if is_choseong(c as u32) {
-                self.first = Some(c);
+    /// Feed a run of text that didn't come from the keyboard, such as a
+    /// paste or a remote message, in whatever normal form it arrived.
+    pub fn push_str(&mut self, text: &str) {
+        for ch in canonicalize(text).chars() {
+            self.push_char(ch);
+        }
+    }
+
+    fn apply_char(&mut self, ch: char) {
+        if !self.romaja.is_empty() {
+            self.commit();
+        }
+
+        if is_punctuation(&ch) {
+            self.commit();
+            self.committed.push(ch);
+            return;
+        }
+
+        if self.old_hangeul {
+            // sebeolsik roles are re-derived from the letter sequence
+            let letter = to_compat(ch).unwrap_or(ch);
+            if is_old_letter(letter) {
+                self.push_old(letter);
+                return;
             }
-            return Some(c);
+        }
 
-        // start looking at the possibilities
-        } else if self.first.is_some() && self.second.is_none() {
-            return None;
+        // Sebeolsik jamo know their own position
+        if conjoining_role(ch).is_some() {
+            self.push_positional(ch);
+            return;
+        }
+        if self.composing_is_positional() {
+            self.commit();
+        }
+
+        let code = ch as u32;
+
+        // A vowel may steal the final consonant of the previous syllable
+        if is_moeum(code) && self.resyllabify(ch) {
+            return;
+        }
+
+        // A finished syllable (pasted, from the OS IME) commits as typed
+        if is_syllable(ch) {
+            self.commit();
+            self.commit_text(ch.to_string());
+            return;
+        }
+
+        // If not Hangeul jamo, commit immediately
+        if !is_jamo(code) && !is_compat_jamo(code) {
+            self.commit();
+            self.committed.push(ch);
+            return;
+        }
+
+        // jamo -> push into buffer
+        self.composing.push(ch);
+        if self.composing.len() > MAX_COMPOSING {
+            let dropped = self.composing.remove(0);
+            self.commit_text(dropped.to_string());
+        }
+
+        self.commit_completed_prefix();
+    }
+
+    /// Feed one Latin character in romaja mode. Letters (and boundary marks
+    /// inside a word) extend the romanized word shown as preedit; anything
+    /// else is not consumed and should go through `push_char`, which commits
+    /// the word first.
+    pub fn push_romaja(&mut self, ch: char) -> bool {
+        let in_word = !self.romaja.is_empty();
+        if !(ch.is_ascii_alphabetic() || (in_word && is_boundary_mark(ch))) {
+            return false;
+        }
+        self.save_snapshot();
+        if !in_word {
+            self.commit();
+        }
+        self.romaja.push(ch.to_ascii_lowercase());
+        true
+    }
+
+    /// Finalize the composing buffer into the committed text.
+    pub fn commit(&mut self) {
+        if !self.romaja.is_empty() {
+            let hangeul = romaja_to_hangeul(&self.romaja);
+            for syllable in hangeul.chars() {
+                self.commit_text(syllable.to_string());
+            }
+            self.romaja.clear();
+            return;
+        }
+
+        if self.old_hangeul {
+            for syllable in split_syllables(&self.composing) {
+                self.commit_text(syllable.render());
+            }
+            self.composing.clear();
+            return;
+        }
+
+        if self.composing_is_positional() {
+            let preedit = self.preedit();
+            self.commit_text(preedit);
+            self.composing.clear();
+            return;
+        }
+
+        let (clusters, _) = cluster_jamo_with_spans(&self.composing);
+
+        if let Some(syllable) = collapse_to_syllable(&clusters) {
+            self.commit_text(syllable.to_string());
         } else {
+            self.commit_text(clusters.into_iter().collect());
+        }
+        self.composing.clear();
+    }
+
+    /// Break the line at the cursor, within the same stanza (Shift+Enter).
+    pub fn newline(&mut self) {
+        self.save_snapshot();
+        self.commit();
+        self.committed.push('\n');
+    }
+
+    /// Finalize and hand back the whole line (or stanza), leaving the
+    /// composer empty. Trailing line breaks are dropped.
+    pub fn submit(&mut self) -> String {
+        self.commit();
+        self.undo.clear();
+        let mut line = std::mem::take(&mut self.committed);
+        line.push_str(&std::mem::take(&mut self.after));
+        line.truncate(line.trim_end_matches('\n').len());
+        self.emit(ComposerEvent::LineSubmitted(line.clone()));
+        line
+    }
+
+    pub fn clear(&mut self) {
+        self.committed.clear();
+        self.after.clear();
+        self.composing.clear();
+        self.romaja.clear();
+        self.undo.clear();
+    }
+
+    /// Undo the last keystroke, restoring the preedit exactly as it was
+    /// (과 → 고, 닭 → 달). Without history, the last committed syllable is
+    /// reopened minus its last jamo.
+    pub fn backspace(&mut self) {
+        if !self.is_empty() {
+            self.emit(ComposerEvent::Backspaced);
+        }
+        if let Some(snapshot) = self.undo.pop() {
+            self.committed = snapshot.committed;
+            self.after = snapshot.after;
+            self.composing = snapshot.composing;
+            self.romaja = snapshot.romaja;
+            return;
+        }
+        self.backspace_without_history();
+    }
+
+    fn backspace_without_history(&mut self) {
+        // Romaja: drop the last Latin letter of the word
+        if self.romaja.pop().is_some() {
+            return;
+        }
+
+        // Pop from composing if it has anything
+        if self.composing.pop().is_some() {
+            return;
+        }
+
+        // Composing is empty → reopen the last committed syllable.
+        // Anything that doesn't decompose (Latin, symbols) is simply removed.
+        let Some(last) = self.committed.pop() else {
+            return;
+        };
+        let mut keystrokes = syllable_keystrokes(last);
+        if !keystrokes.is_empty() {
+            keystrokes.pop();
+            self.composing = keystrokes;
+        }
+    }
+
+    /// The run of Hangeul syllables ending at the cursor, once the preedit
+    /// is committed: the word Hanja conversion works on.
+    pub fn word_before_cursor(&mut self) -> String {
+        self.settle();
+        let mut word: Vec<char> = self
+            .committed
+            .chars()
+            .rev()
+            .take_while(|c| is_syllable(*c))
+            .collect();
+        word.reverse();
+        word.into_iter().collect()
+    }
+
+    /// Replace the last `chars` characters before the cursor, e.g. a
+    /// Hangeul reading with the Hanja picked for it.
+    pub fn replace_before_cursor(&mut self, chars: usize, replacement: &str) {
+        self.settle();
+        for _ in 0..chars {
+            self.committed.pop();
+        }
+        self.commit_text(replacement.to_owned());
+    }
+
+    /// Delete the committed character after the cursor.
+    pub fn delete(&mut self) {
+        self.settle();
+        if let Some(next) = self.after.chars().next() {
+            self.after.drain(..next.len_utf8());
+        }
+    }
+
+    /************************* Cursor ********************/
+
+    pub fn move_left(&mut self) {
+        self.settle();
+        if let Some(prev) = self.committed.pop() {
+            self.after.insert(0, prev);
+        }
+    }
+
+    pub fn move_right(&mut self) {
+        self.settle();
+        if let Some(next) = self.after.chars().next() {
+            self.after.drain(..next.len_utf8());
+            self.committed.push(next);
+        }
+    }
+
+    pub fn move_home(&mut self) {
+        self.set_cursor(0);
+    }
+
+    pub fn move_end(&mut self) {
+        self.set_cursor(usize::MAX);
+    }
+
+    /// Put the cursor before the `index`-th char of the line (clamped),
+    /// e.g. where the input field was clicked.
+    pub fn set_cursor(&mut self, index: usize) {
+        self.settle();
+        let mut line = std::mem::take(&mut self.committed);
+        line.push_str(&std::mem::take(&mut self.after));
+
+        let split = line
+            .char_indices()
+            .nth(index)
+            .map_or(line.len(), |(byte, _)| byte);
+        self.after = line.split_off(split);
+        self.committed = line;
+    }
+
+    /************************* Internals ********************/
+
+    /// Commit before the cursor moves or text behind it changes. Undo
+    /// history only covers typing at one spot, so it is dropped too and
+    /// backspace goes back to deleting before the cursor.
+    fn settle(&mut self) {
+        self.commit();
+        self.undo.clear();
+    }
+
+    /// Move finished text into `committed` and announce it.
+    fn commit_text(&mut self, text: String) {
+        if text.is_empty() {
+            return;
+        }
+        self.committed.push_str(&text);
+        self.emit(ComposerEvent::SyllableCommitted(text));
+    }
+
+    fn emit(&mut self, event: ComposerEvent) {
+        self.events.push(event);
+        self.trim_events();
+    }
+
+    fn trim_events(&mut self) {
+        if self.events.len() > MAX_EVENTS {
+            let excess = self.events.len() - MAX_EVENTS;
+            self.events.drain(..excess);
+        }
+    }
+
+    /// The role a typed jamo took: its own for conjoining jamo, otherwise
+    /// read off the syllable it just went into.
+    fn typed_role(&self, ch: char) -> Option<JamoRole> {
+        if let Some(role) = conjoining_role(ch) {
+            return Some(role);
+        }
+        let consonant_role = |is_final: bool| {
+            if is_final {
+                JamoRole::Jongseong
+            } else {
+                JamoRole::Choseong
+            }
+        };
+
+        if self.old_hangeul && is_old_letter(ch) {
+            if is_vowel_letter(ch) {
+                return Some(JamoRole::Jungseong);
+            }
+            let syllables = split_syllables(&self.composing);
+            let is_final = syllables.last().is_some_and(|s| s.has_final());
+            return Some(consonant_role(is_final));
+        }
+
+        let code = ch as u32;
+        if is_moeum(code) {
+            return Some(JamoRole::Jungseong);
+        }
+        if !is_jaeum(code) {
             return None;
         }
+        let is_final = self
+            .preedit()
+            .chars()
+            .last()
+            .and_then(|last| decompose_char(&last).ok())
+            .is_some_and(|(_, _, tail)| tail.is_some());
+        Some(consonant_role(is_final))
+    }
+
+    /// Whether the preedit is exactly one complete syllable.
+    fn preedit_is_syllable(&self) -> bool {
+        if self.old_hangeul && self.romaja.is_empty() {
+            let syllables = split_syllables(&self.composing);
+            return syllables.len() == 1 && syllables[0].is_complete();
+        }
+        let preedit = self.preedit();
+        let mut chars = preedit.chars();
+        matches!((chars.next(), chars.next()), (Some(one), None) if is_syllable(one))
+    }
+
+    fn save_snapshot(&mut self) {
+        if self.undo.len() == MAX_UNDO {
+            self.undo.remove(0);
+        }
+        self.undo.push(Snapshot {
+            committed: self.committed.clone(),
+            after: self.after.clone(),
+            composing: self.composing.clone(),
+            romaja: self.romaja.clone(),
+        });
     }
+
+    fn composing_is_positional(&self) -> bool {
+        self.composing
+            .first()
+            .is_some_and(|c| conjoining_role(*c).is_some())
+    }
+
+    /// Conjoining jamo extend the current syllable while they fit the
+    /// choseong → jungseong → jongseong order, otherwise start a new one.
+    fn push_positional(&mut self, ch: char) {
+        if !self.composing_is_positional() {
+            self.commit();
+        }
+
+        self.composing.push(ch);
+        if parse_positional(&self.composing).is_none() {
+            self.composing.pop();
+            self.commit();
+            self.composing.push(ch);
+        }
+    }
+
+    /// Old Hangeul letters: keep only the unfinished syllable composing.
+    fn push_old(&mut self, letter: char) {
+        self.composing.push(letter);
+
+        let syllables = split_syllables(&self.composing);
+        if let Some((current, finished)) = syllables.split_last() {
+            for syllable in finished {
+                self.commit_text(syllable.render());
+            }
+            self.composing = current.letters();
+        }
+    }
+
+    /// Handles a vowel typed right after a syllable with a final consonant:
+    /// the final (or the second half of a compound final) moves over to
+    /// start the new syllable, e.g. 갉 + ㅏ → 갈 / 가.
+    fn resyllabify(&mut self, vowel: char) -> bool {
+        // A) the syllable is already committed
+        if self.composing.is_empty() {
+            let Some(last) = self.committed.chars().last() else {
+                return false;
+            };
+            let Some((base, moved)) = split_syllable_final(last) else {
+                return false;
+            };
+            self.committed.pop();
+            self.committed.push(base);
+            self.composing = vec![moved, vowel];
+            self.emit_resyllabified(last, base, moved, vowel);
+            return true;
+        }
+
+        // B) it's fully in the composing buffer as one syllable
+        let (clusters, _) = cluster_jamo_with_spans(&self.composing);
+        let Some(syllable) = collapse_to_syllable(&clusters) else {
+            return false;
+        };
+        let Some((base, moved)) = split_syllable_final(syllable) else {
+            return false;
+        };
+        self.emit_resyllabified(syllable, base, moved, vowel);
+        self.commit_text(base.to_string());
+        self.composing = vec![moved, vowel];
+        true
+    }
+
+    fn emit_resyllabified(&mut self, from: char, base: char, moved: char, vowel: char) {
+        if let Ok(next) = compose_char(&moved, &vowel, None) {
+            let to = (base, next);
+            self.emit(ComposerEvent::SyllableResyllabified { from, to });
+        }
+    }
+
+    /// Commit any prefix clusters that can't extend.
+    fn commit_completed_prefix(&mut self) {
+        loop {
+            let (clusters, spans) = cluster_jamo_with_spans(&self.composing);
+            // if the *whole* clustered buffer is a valid syllable, stop
+            if collapse_to_syllable(&clusters).is_some() {
+                break;
+            }
+            // otherwise find the longest prefix that *is* a syllable
+            let mut did = false;
+            for i in (1..clusters.len()).rev() {
+                if let Some(syllable) = collapse_to_syllable(&clusters[..i]) {
+                    self.commit_text(syllable.to_string());
+
+                    // remove exactly sum(spans[0..i]) raw chars
+                    let raw_to_remove: usize = spans[..i].iter().sum();
+                    self.composing.drain(0..raw_to_remove);
+                    did = true;
+                    break;
+                }
+            }
+            if !did {
+                break;
+            }
+        }
+    }
+}
+
+// One syllable assembled from conjoining jamo, held as compatibility jamo.
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+struct PositionalSyllable {
+    cho: Option<char>,
+    jung: Option<char>,
+    jong: Option<char>,
+}
+
+impl std::fmt::Display for PositionalSyllable {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if let (Some(cho), Some(jung)) = (self.cho, self.jung) {
+            if let Ok(syllable) = compose_char(&cho, &jung, self.jong.as_ref()) {
+                return write!(f, "{}", syllable);
+            }
+        }
+        // Incomplete syllable: show the loose jamo
+        for jamo in [self.cho, self.jung, self.jong].into_iter().flatten() {
+            write!(f, "{}", jamo)?;
+        }
+        Ok(())
+    }
+}
+
+/// Parse raw conjoining jamo as a single syllable. Each slot takes at most
+/// two keystrokes (ᄀ+ᄀ → ㄲ, ㅗ+ㅏ → ㅘ, ㄹ+ㄱ → ㄺ) and slots must come in order.
+fn parse_positional(raw: &[char]) -> Option<PositionalSyllable> {
+    let mut syllable = PositionalSyllable::default();
+    let mut last_role = None;
+    let mut strokes_in_slot = 0;
+
+    for &ch in raw {
+        let role = conjoining_role(ch)?;
+        let jamo = to_compat(ch)?;
+        let slot = match role {
+            JamoRole::Choseong => &mut syllable.cho,
+            JamoRole::Jungseong => &mut syllable.jung,
+            JamoRole::Jongseong => &mut syllable.jong,
+        };
+
+        if last_role == Some(role) {
+            strokes_in_slot += 1;
+            if strokes_in_slot > 2 {
+                return None;
+            }
+            let current = (*slot)?;
+            *slot = Some(match role {
+                JamoRole::Choseong => try_double_consonant(current, jamo)?,
+                JamoRole::Jungseong => try_combine_vowel(current, jamo)?,
+                JamoRole::Jongseong => try_combine_final(current, jamo)
+                    .or_else(|| try_double_consonant(current, jamo))?,
+            });
+        } else {
+            // roles only move forward
+            if last_role.is_some_and(|last| role_order(last) > role_order(role)) {
+                return None;
+            }
+            *slot = Some(jamo);
+            strokes_in_slot = 1;
+            last_role = Some(role);
+        }
+    }
+    Some(syllable)
+}
+
+fn role_order(role: JamoRole) -> u8 {
+    match role {
+        JamoRole::Choseong => 0,
+        JamoRole::Jungseong => 1,
+        JamoRole::Jongseong => 2,
+    }
+}
+
+/// Pressing a plain consonant twice gives its tense form.
+fn try_double_consonant(a: char, b: char) -> Option<char> {
+    match (a, b) {
+        ('ㄱ', 'ㄱ') => Some('ㄲ'),
+        ('ㄷ', 'ㄷ') => Some('ㄸ'),
+        ('ㅂ', 'ㅂ') => Some('ㅃ'),
+        ('ㅅ', 'ㅅ') => Some('ㅆ'),
+        ('ㅈ', 'ㅈ') => Some('ㅉ'),
+        _ => None,
+    }
+}
+
+/// Split a syllable with a final into (syllable without the moved part, moved consonant).
+/// Returns None when the character is not a syllable or has no final.
+fn split_syllable_final(syllable: char) -> Option<(char, char)> {
+    let Ok((lead, vowel, Some(tail))) = decompose_char(&syllable) else {
+        return None;
+    };
+    let (first_tail, second_tail) = split_final_jamo(tail);
+    let base = compose_char(&lead, &vowel, first_tail.as_ref()).ok()?;
+    Some((base, second_tail))
+}
+
+/// Collapse at most three elements (choseong, jungseong, jongseong) into one syllable.
+pub fn collapse_to_syllable(clustered: &[char]) -> Option<char> {
+    match clustered.len() {
+        0 => None,
+        1 => Some(clustered[0]),
+        2 => compose_char(&clustered[0], &clustered[1], None).ok(),
+        3 => compose_char(&clustered[0], &clustered[1], Some(&clustered[2])).ok(),
+        _ => None,
+    }
+}
+
+/// Try combining two simple jungseong into one compound vowel.
+pub fn try_combine_vowel(a: char, b: char) -> Option<char> {
+    match (a, b) {
+        ('ㅗ', 'ㅏ') => Some('ㅘ'),
+        ('ㅗ', 'ㅐ') => Some('ㅙ'),
+        ('ㅗ', 'ㅣ') => Some('ㅚ'),
+        ('ㅜ', 'ㅓ') => Some('ㅝ'),
+        ('ㅜ', 'ㅔ') => Some('ㅞ'),
+        ('ㅜ', 'ㅣ') => Some('ㅟ'),
+        ('ㅡ', 'ㅣ') => Some('ㅢ'),
+        _ => None,
+    }
+}
+
+/// Try combining two simple jongseong into one compound final.
+pub fn try_combine_final(a: char, b: char) -> Option<char> {
+    match (a, b) {
+        ('ㄱ', 'ㅅ') => Some('ㄳ'),
+        ('ㄴ', 'ㅈ') => Some('ㄵ'),
+        ('ㄴ', 'ㅎ') => Some('ㄶ'),
+        ('ㄹ', 'ㄱ') => Some('ㄺ'),
+        ('ㄹ', 'ㅁ') => Some('ㄻ'),
+        ('ㄹ', 'ㅂ') => Some('ㄼ'),
+        ('ㄹ', 'ㅅ') => Some('ㄽ'),
+        ('ㄹ', 'ㅌ') => Some('ㄾ'),
+        ('ㄹ', 'ㅍ') => Some('ㄿ'),
+        ('ㄹ', 'ㅎ') => Some('ㅀ'),
+        ('ㅂ', 'ㅅ') => Some('ㅄ'),
+        _ => None,
+    }
+}
+
+/// Inverse of `try_combine_vowel`.
+pub fn split_compound_vowel(v: char) -> Option<(char, char)> {
+    match v {
+        'ㅘ' => Some(('ㅗ', 'ㅏ')),
+        'ㅙ' => Some(('ㅗ', 'ㅐ')),
+        'ㅚ' => Some(('ㅗ', 'ㅣ')),
+        'ㅝ' => Some(('ㅜ', 'ㅓ')),
+        'ㅞ' => Some(('ㅜ', 'ㅔ')),
+        'ㅟ' => Some(('ㅜ', 'ㅣ')),
+        'ㅢ' => Some(('ㅡ', 'ㅣ')),
+        _ => None,
+    }
+}
+
+/// The dubeolsik keystrokes that type a syllable, compounds split apart:
+/// 괅 → ㄱ ㅗ ㅏ ㄹ ㄱ. Empty if `syllable` isn't a Hangeul syllable.
+pub fn syllable_keystrokes(syllable: char) -> Vec<char> {
+    let Ok((lead, vowel, tail)) = decompose_char(&syllable) else {
+        return Vec::new();
+    };
+    let mut keystrokes = vec![lead];
+    match split_compound_vowel(vowel) {
+        Some((first, second)) => keystrokes.extend([first, second]),
+        None => keystrokes.push(vowel),
+    }
+    if let Some(tail) = tail {
+        let (first, second) = split_final_jamo(tail);
+        keystrokes.extend(first);
+        keystrokes.push(second);
+    }
+    keystrokes
+}
+
+/// Returns (clusters, spans) where:
+///  - `clusters[k]` is the k-th merged jamo (e.g. ㄹ+ㄱ → ㄺ), and
+///  - `spans[k]` is how many raw characters that cluster consumed (1 or 2).
+pub fn cluster_jamo_with_spans(raw: &[char]) -> (Vec<char>, Vec<usize>) {
+    let mut clusters = Vec::new();
+    let mut spans = Vec::new();
+    let mut i = 0;
+    while i < raw.len() {
+        // try double‑vowel first
+        if i + 1 < raw.len() {
+            if let Some(v) = try_combine_vowel(raw[i], raw[i + 1]) {
+                clusters.push(v);
+                spans.push(2);
+                i += 2;
+                continue;
+            }
+        }
+        // try double‑final
+        if i + 1 < raw.len() {
+            if let Some(f) = try_combine_final(raw[i], raw[i + 1]) {
+                clusters.push(f);
+                spans.push(2);
+                i += 2;
+                continue;
+            }
+        }
+        // else single jamo
+        clusters.push(raw[i]);
+        spans.push(1);
+        i += 1;
+    }
+    (clusters, spans)
+}
+
+/// Given a final jamo (possibly compound), return (first, second).
+/// If it isn’t compound, we interpret that as (no‑final, final).
+pub fn split_final_jamo(j: char) -> (Option<char>, char) {
+    match j {
+        'ㄳ' => (Some('ㄱ'), 'ㅅ'),
+        'ㄵ' => (Some('ㄴ'), 'ㅈ'),
+        'ㄶ' => (Some('ㄴ'), 'ㅎ'),
+        'ㄺ' => (Some('ㄹ'), 'ㄱ'),
+        'ㄻ' => (Some('ㄹ'), 'ㅁ'),
+        'ㄼ' => (Some('ㄹ'), 'ㅂ'),
+        'ㄽ' => (Some('ㄹ'), 'ㅅ'),
+        'ㄾ' => (Some('ㄹ'), 'ㅌ'),
+        'ㄿ' => (Some('ㄹ'), 'ㅍ'),
+        'ㅀ' => (Some('ㄹ'), 'ㅎ'),
+        'ㅄ' => (Some('ㅂ'), 'ㅅ'),
+        // not a compound final
+        _ => (None, j),
+    }
+}
+
+/// Determines if a character is considered punctuation or a space.
+pub fn is_punctuation(c: &char) -> bool {
+    matches!(
+        c,
+        '!' | '"'
+            | '#'
+            | '$'
+            | '%'
+            | '&'
+            | '\''
+            | '('
+            | ')'
+            | '*'
+            | '+'
+            | ','
+            | '-'
+            | '.'
+            | '/'
+            | ':'
+            | ';'
+            | '<'
+            | '='
+            | '>'
+            | '?'
+            | '@'
+            | '['
+            | '\\'
+            | ']'
+            | '^'
+            | '_'
+            | '`'
+            | '{'
+            | '|'
+            | '}'
+            | '~'
+            | ' '
+    )
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    fn type_str(composer: &mut HangeulComposer, jamo: &str) {
+        for ch in jamo.chars() {
+            composer.push_char(ch);
+        }
+    }
+
+    #[test]
+    fn test_single_syllable_preedit() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅎㅏㄴ");
+        assert_eq!(composer.committed(), "");
+        assert_eq!(composer.preedit(), "한");
+    }
+
+    #[test]
+    fn test_compound_vowel_and_final() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄱㅗㅏㄹㄱ");
+        assert_eq!(composer.display(), "괅");
+    }
+
+    #[test]
+    fn test_vowel_steals_final() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅎㅏㄴㄱㅡㄹ");
+        assert_eq!(composer.committed(), "한");
+        assert_eq!(composer.preedit(), "글");
+
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄷㅏㄹㄱㅇㅣ");
+        assert_eq!(composer.display(), "닭이");
+
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄷㅏㄹㄱㅏ");
+        assert_eq!(composer.committed(), "달");
+        assert_eq!(composer.preedit(), "가");
+    }
+
+    #[test]
+    fn test_vowel_after_committed_final() {
+        let mut composer = HangeulComposer::new();
+        composer.push_char('각');
+        composer.push_char('ㅏ');
+        assert_eq!(composer.committed(), "가");
+        assert_eq!(composer.preedit(), "가");
+    }
+
+    #[test]
+    fn test_punctuation_commits() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅎㅏㄴ ㄱㅡㄹ.");
+        assert_eq!(composer.committed(), "한 글.");
+        assert!(composer.preedit().is_empty());
+    }
+
+    #[test]
+    fn test_positional_syllables() {
+        // 과 + ㄺ typed as conjoining jamo: ᄀ ᅩ ᅡ ᆯ ᆨ
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "\u{1100}\u{1169}\u{1161}\u{11AF}\u{11A8}");
+        assert_eq!(composer.preedit(), "괅");
+
+        // a following vowel never steals the final
+        type_str(&mut composer, "\u{110B}\u{1161}");
+        assert_eq!(composer.committed(), "괅");
+        assert_eq!(composer.preedit(), "아");
+    }
+
+    #[test]
+    fn test_positional_incomplete() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "\u{1100}\u{1100}\u{1103}");
+        assert_eq!(composer.committed(), "ㄲ");
+        assert_eq!(composer.preedit(), "ㄷ");
+    }
+
+    #[test]
+    fn test_romaja_word() {
+        let mut composer = HangeulComposer::new();
+        for ch in "hunminjeongeum".chars() {
+            assert!(composer.push_romaja(ch));
+        }
+        assert_eq!(composer.committed(), "");
+        assert_eq!(composer.preedit(), "훈민정음");
+
+        assert!(!composer.push_romaja(' '));
+        composer.push_char(' ');
+        assert_eq!(composer.committed(), "훈민정음 ");
+
+        composer.push_romaja('h');
+        composer.push_romaja('a');
+        composer.push_romaja('n');
+        composer.backspace();
+        assert_eq!(composer.preedit(), "하");
+    }
+
+    // Every compound vowel in try_combine_vowel
+    const COMPOUND_VOWELS: [(char, char, char); 7] = [
+        ('ㅗ', 'ㅏ', 'ㅘ'),
+        ('ㅗ', 'ㅐ', 'ㅙ'),
+        ('ㅗ', 'ㅣ', 'ㅚ'),
+        ('ㅜ', 'ㅓ', 'ㅝ'),
+        ('ㅜ', 'ㅔ', 'ㅞ'),
+        ('ㅜ', 'ㅣ', 'ㅟ'),
+        ('ㅡ', 'ㅣ', 'ㅢ'),
+    ];
+
+    // Every compound final in try_combine_final
+    const COMPOUND_FINALS: [(char, char, char); 11] = [
+        ('ㄱ', 'ㅅ', 'ㄳ'),
+        ('ㄴ', 'ㅈ', 'ㄵ'),
+        ('ㄴ', 'ㅎ', 'ㄶ'),
+        ('ㄹ', 'ㄱ', 'ㄺ'),
+        ('ㄹ', 'ㅁ', 'ㄻ'),
+        ('ㄹ', 'ㅂ', 'ㄼ'),
+        ('ㄹ', 'ㅅ', 'ㄽ'),
+        ('ㄹ', 'ㅌ', 'ㄾ'),
+        ('ㄹ', 'ㅍ', 'ㄿ'),
+        ('ㄹ', 'ㅎ', 'ㅀ'),
+        ('ㅂ', 'ㅅ', 'ㅄ'),
+    ];
+
+    #[test]
+    fn test_backspace_compound_vowels() {
+        for (first, second, compound) in COMPOUND_VOWELS {
+            let mut composer = HangeulComposer::new();
+            type_str(&mut composer, &format!("ㄱ{}{}", first, second));
+            let full = compose_char(&'ㄱ', &compound, None).unwrap();
+            assert_eq!(composer.preedit(), full.to_string());
+
+            composer.backspace();
+            let half = compose_char(&'ㄱ', &first, None).unwrap();
+            assert_eq!(composer.preedit(), half.to_string(), "{}", compound);
+        }
+    }
+
+    #[test]
+    fn test_backspace_compound_finals() {
+        for (first, second, compound) in COMPOUND_FINALS {
+            let mut composer = HangeulComposer::new();
+            type_str(&mut composer, &format!("ㄱㅏ{}{}", first, second));
+            let full = compose_char(&'ㄱ', &'ㅏ', Some(&compound)).unwrap();
+            assert_eq!(composer.preedit(), full.to_string());
+
+            composer.backspace();
+            let half = compose_char(&'ㄱ', &'ㅏ', Some(&first)).unwrap();
+            assert_eq!(composer.preedit(), half.to_string(), "{}", compound);
+        }
+    }
+
+    #[test]
+    fn test_backspace_is_inverse_of_typing() {
+        let mut composer = HangeulComposer::new();
+        let mut states = vec![composer.display()];
+        for ch in "ㄷㅏㄹㄱㅇㅣ ㅇㅗㅏㅆㄷㅏ.".chars() {
+            composer.push_char(ch);
+            states.push(composer.display());
+        }
+        assert_eq!(composer.display(), "닭이 왔다.");
+
+        while let Some(expected) = states.pop() {
+            assert_eq!(composer.display(), expected);
+            composer.backspace();
+        }
+        assert!(composer.is_empty());
+    }
+
+    #[test]
+    fn test_backspace_undoes_resyllabification() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄷㅏㄹㄱㅏ");
+        assert_eq!(composer.display(), "달가");
+        composer.backspace();
+        assert_eq!(composer.committed(), "");
+        assert_eq!(composer.preedit(), "닭");
+    }
+
+    #[test]
+    fn test_backspace_without_history() {
+        let mut composer = HangeulComposer::new();
+        composer.committed = "a과닭".to_owned();
+
+        composer.backspace();
+        assert_eq!(composer.display(), "a과달");
+        for _ in 0..3 {
+            composer.backspace();
+        }
+        assert_eq!(composer.display(), "a과");
+        composer.backspace();
+        assert_eq!(composer.display(), "a고");
+        for _ in 0..2 {
+            composer.backspace();
+        }
+        assert_eq!(composer.display(), "a");
+        composer.backspace();
+        assert!(composer.is_empty());
+    }
+
+    #[test]
+    fn test_old_hangeul_mode() {
+        let mut composer = HangeulComposer::new();
+        composer.set_old_hangeul(true);
+        type_str(&mut composer, "ㄴㆍㄹㅏㅅㅁㅏㄹㅆㆍㅁ");
+        assert_eq!(
+            composer.display(),
+            "\u{1102}\u{119E}랏말\u{110A}\u{119E}\u{11B7}"
+        );
+
+        // backspace still undoes one letter at a time, ㅆ going back to
+        // the final cluster it was released from
+        composer.backspace();
+        assert_eq!(composer.preedit(), "\u{110A}\u{119E}");
+        composer.backspace();
+        assert_eq!(composer.preedit(), "\u{1106}\u{1161}\u{11D6}");
+    }
+
+    #[test]
+    fn test_cursor_movement() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅎㅏㄴㄱㅡㄹ");
+        assert_eq!(composer.cursor(), 2);
+
+        // moving commits the preedit
+        composer.move_left();
+        assert_eq!(composer.committed(), "한글");
+        assert_eq!(composer.cursor(), 1);
+        composer.move_home();
+        assert_eq!(composer.cursor(), 0);
+        composer.move_right();
+        composer.move_right();
+        composer.move_right();
+        assert_eq!(composer.cursor(), 2);
+        composer.set_cursor(1);
+        assert_eq!(composer.cursor(), 1);
+        composer.move_end();
+        assert_eq!(composer.cursor(), 2);
+    }
+
+    #[test]
+    fn test_compose_mid_line() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅎㅏㄴㄱㅡㄹ");
+        composer.move_left();
+
+        // 한|글 → 한국|글, composing in the middle
+        type_str(&mut composer, "ㄱㅜㄱ");
+        assert_eq!(composer.display(), "한국글");
+        assert_eq!(composer.preedit(), "국");
+        assert_eq!(composer.cursor(), 2);
+
+        // backspace undoes keystrokes at the cursor
+        composer.backspace();
+        assert_eq!(composer.display(), "한구글");
+
+        // a vowel still resyllabifies the final in front of it
+        type_str(&mut composer, "ㄹㅓ");
+        assert_eq!(composer.display(), "한구러글");
+
+        assert_eq!(composer.submit(), "한구러글");
+        assert!(composer.is_empty());
+    }
+
+    #[test]
+    fn test_delete_and_backspace_mid_line() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄱㅏ ㄴㅏ ㄷㅏ");
+        composer.set_cursor(1);
+
+        // delete removes the char after the cursor
+        composer.delete();
+        assert_eq!(composer.display(), "가나 다");
+        assert_eq!(composer.cursor(), 1);
+
+        // without history, backspace reopens the syllable before the cursor
+        composer.backspace();
+        assert_eq!(composer.display(), "ㄱ나 다");
+        composer.backspace();
+        assert_eq!(composer.display(), "나 다");
+        assert_eq!(composer.cursor(), 0);
+    }
+
+    #[test]
+    fn test_stanza() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄷㅗㅇㅊㅏㅇ");
+        composer.newline();
+        assert_eq!(composer.display(), "동창\n");
+        assert_eq!(composer.cursor(), 3);
+
+        // the break is one keystroke for backspace
+        type_str(&mut composer, "ㅅ");
+        composer.backspace();
+        composer.backspace();
+        assert_eq!(composer.display(), "동창");
+
+        composer.newline();
+        type_str(&mut composer, "ㅅㅗ");
+        assert_eq!(composer.current_word(), "소");
+        composer.newline();
+        assert_eq!(composer.submit(), "동창\n소");
+        assert!(composer.is_empty());
+    }
+
+    #[test]
+    fn test_events_for_typing() {
+        use ComposerEvent::*;
+        use JamoRole::*;
+
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄷㅏㄹㄱ");
+        assert_eq!(
+            composer.drain_events(),
+            vec![
+                JamoTyped {
+                    jamo: 'ㄷ',
+                    role: Choseong
+                },
+                JamoTyped {
+                    jamo: 'ㅏ',
+                    role: Jungseong
+                },
+                SyllableFormed("다".into()),
+                JamoTyped {
+                    jamo: 'ㄹ',
+                    role: Jongseong
+                },
+                SyllableFormed("달".into()),
+                JamoTyped {
+                    jamo: 'ㄱ',
+                    role: Jongseong
+                },
+                SyllableFormed("닭".into()),
+            ]
+        );
+
+        // ㄺ + ㅏ → ㄹ / 가
+        composer.push_char('ㅏ');
+        assert_eq!(
+            composer.drain_events(),
+            vec![
+                JamoTyped {
+                    jamo: 'ㅏ',
+                    role: Jungseong
+                },
+                SyllableResyllabified {
+                    from: '닭',
+                    to: ('달', '가')
+                },
+                SyllableCommitted("달".into()),
+                SyllableFormed("가".into()),
+            ]
+        );
+
+        composer.backspace();
+        assert_eq!(composer.submit(), "닭");
+        assert_eq!(
+            composer.drain_events(),
+            vec![
+                Backspaced,
+                SyllableCommitted("닭".into()),
+                LineSubmitted("닭".into()),
+            ]
+        );
+        assert!(composer.drain_events().is_empty());
+    }
+
+    #[test]
+    fn test_events_for_punctuation() {
+        use ComposerEvent::*;
+
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄱㅏ ");
+        let events = composer.drain_events();
+        assert_eq!(events.last(), Some(&SyllableCommitted("가".into())));
+    }
+
+    #[test]
+    fn test_events_for_precomposed() {
+        use ComposerEvent::*;
+
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㄱㅏ");
+        composer.drain_events();
+        composer.push_char('한');
+        assert_eq!(
+            composer.drain_events(),
+            vec![
+                SyllableCommitted("가".into()),
+                SyllableCommitted("한".into())
+            ]
+        );
+        assert_eq!(composer.submit(), "가한");
+    }
+
+    #[test]
+    fn test_push_str_any_normal_form() {
+        let nfd = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}";
+        for text in [nfd, "한글", "ㅎㅏㄴㄱㅡㄹ"] {
+            let mut composer = HangeulComposer::new();
+            composer.push_str(text);
+            assert_eq!(composer.submit(), "한글");
+        }
+
+        // pasted syllables reach the event stream like typed ones
+        let mut composer = HangeulComposer::new();
+        composer.push_str(nfd);
+        let committed: Vec<ComposerEvent> = composer
+            .drain_events()
+            .into_iter()
+            .filter(|e| matches!(e, ComposerEvent::SyllableCommitted(_)))
+            .collect();
+        assert_eq!(
+            committed,
+            vec![
+                ComposerEvent::SyllableCommitted("한".into()),
+                ComposerEvent::SyllableCommitted("글".into()),
+            ]
+        );
+
+        // a trailing incomplete syllable stays open for typing
+        let mut composer = HangeulComposer::new();
+        composer.push_str("\u{1112}\u{1161}\u{11AB}\u{1100}");
+        composer.push_char('\u{1173}');
+        assert_eq!(composer.display(), "한그");
+    }
+
+    #[test]
+    fn test_replace_word_before_cursor() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅇㅜㄹㅣ ㅎㅏㄴㄱㅜㄱ");
+        assert_eq!(composer.word_before_cursor(), "한국");
+        composer.replace_before_cursor(2, "韓國");
+        assert_eq!(composer.display(), "우리 韓國");
+
+        // in the middle of the line, the rest stays behind the cursor
+        composer.set_cursor(2);
+        assert_eq!(composer.word_before_cursor(), "우리");
+        composer.replace_before_cursor(1, "理");
+        assert_eq!(composer.display(), "우理 韓國");
+        assert_eq!(composer.cursor(), 2);
+    }
+
+    #[test]
+    fn test_current_word() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅇㅜㄹㅣ ㅎㅏㄴㄱㅜ");
+        assert_eq!(composer.current_word(), "한구");
+
+        // completing replaces the word, preedit included
+        let word = composer.current_word();
+        composer.replace_before_cursor(word.chars().count(), "한국어");
+        assert_eq!(composer.display(), "우리 한국어");
+        assert_eq!(composer.current_word(), "한국어");
+    }
+
     #[test]
-    fn test_process() {
-        let mut input = 'ㅏ';
-        let new_string = hangeul::is_moeum(12623);
-        println!("moeum: {:?}", new_string);
+    fn test_submit_empties() {
+        let mut composer = HangeulComposer::new();
+        type_str(&mut composer, "ㅁㅏㄹㅆㅡㅁ");
+        assert_eq!(composer.submit(), "말씀");
+        assert!(composer.is_empty());
     }
 }
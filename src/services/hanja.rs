@@ -0,0 +1,178 @@
+// src/services/hanja.rs
+//
+// Hangeul → Hanja conversion from a local dictionary (assets/hanja.txt).
+// The dictionary maps a reading to its candidates; the performer picks one
+// for the word in front of the cursor from a list in the input window.
+//
+// Dictionary lines are `reading:hanja:meaning`; `#` starts a comment.
+
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HanjaCandidate {
+    pub hanja: String,
+    pub meaning: String,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct HanjaDictionary {
+    entries: HashMap<String, Vec<HanjaCandidate>>,
+}
+
+impl HanjaDictionary {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        Ok(Self::parse(&content))
+    }
+
+    /// Parse dictionary text. Malformed lines are skipped.
+    pub fn parse(content: &str) -> Self {
+        let mut entries: HashMap<String, Vec<HanjaCandidate>> = HashMap::new();
+
+        for line in content.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            let mut fields = line.splitn(3, ':');
+            let (Some(reading), Some(hanja)) = (fields.next(), fields.next()) else {
+                continue;
+            };
+            if reading.is_empty() || hanja.is_empty() {
+                continue;
+            }
+            entries
+                .entry(reading.to_owned())
+                .or_default()
+                .push(HanjaCandidate {
+                    hanja: hanja.to_owned(),
+                    meaning: fields.next().unwrap_or_default().to_owned(),
+                });
+        }
+        Self { entries }
+    }
+
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    pub fn lookup(&self, reading: &str) -> &[HanjaCandidate] {
+        self.entries.get(reading).map_or(&[], Vec::as_slice)
+    }
+
+    /// Candidates for the longest ending of `word` found in the dictionary,
+    /// with that ending's length in chars: 우리대한민국 → (4, [大韓民國]).
+    pub fn lookup_suffix(&self, word: &str) -> Option<(usize, &[HanjaCandidate])> {
+        let chars: Vec<char> = word.chars().collect();
+        (0..chars.len()).find_map(|start| {
+            let suffix: String = chars[start..].iter().collect();
+            self.entries
+                .get(&suffix)
+                .map(|candidates| (chars.len() - start, candidates.as_slice()))
+        })
+    }
+}
+
+// Candidate list open for the word in front of the cursor.
+#[derive(Debug, Clone)]
+pub struct HanjaPicker {
+    pub reading_len: usize, // chars of Hangeul the choice replaces
+    pub candidates: Vec<HanjaCandidate>,
+    pub selected: usize,
+}
+
+impl HanjaPicker {
+    /// Open a picker for the word before the cursor, if anything matches.
+    pub fn open(dictionary: &HanjaDictionary, word: &str) -> Option<Self> {
+        let (reading_len, candidates) = dictionary.lookup_suffix(word)?;
+        Some(Self {
+            reading_len,
+            candidates: candidates.to_vec(),
+            selected: 0,
+        })
+    }
+
+    pub fn next(&mut self) {
+        self.selected = (self.selected + 1) % self.candidates.len();
+    }
+
+    pub fn prev(&mut self) {
+        self.selected = (self.selected + self.candidates.len() - 1) % self.candidates.len();
+    }
+
+    pub fn selected(&self) -> &HanjaCandidate {
+        &self.candidates[self.selected]
+    }
+
+    /// Index of the candidate for a 1-based number key, as shown in the list.
+    pub fn numbered(&self, key: char) -> Option<usize> {
+        let index = (key.to_digit(10)? as usize).checked_sub(1)?;
+        (index < self.candidates.len()).then_some(index)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "\
+# comment
+한:韓:나라
+한:漢:한수
+국:國:나라
+한국:韓國:나라 이름
+broken line
+";
+
+    #[test]
+    fn test_parse_and_lookup() {
+        let dictionary = HanjaDictionary::parse(SAMPLE);
+        assert_eq!(dictionary.len(), 3);
+        let han: Vec<&str> = dictionary
+            .lookup("한")
+            .iter()
+            .map(|c| c.hanja.as_str())
+            .collect();
+        assert_eq!(han, vec!["韓", "漢"]);
+        assert_eq!(dictionary.lookup("한국")[0].meaning, "나라 이름");
+        assert!(dictionary.lookup("없음").is_empty());
+    }
+
+    #[test]
+    fn test_longest_suffix() {
+        let dictionary = HanjaDictionary::parse(SAMPLE);
+        let (len, candidates) = dictionary.lookup_suffix("우리한국").unwrap();
+        assert_eq!(len, 2);
+        assert_eq!(candidates[0].hanja, "韓國");
+        assert_eq!(dictionary.lookup_suffix("나국").unwrap().0, 1);
+        assert!(dictionary.lookup_suffix("나라").is_none());
+    }
+
+    #[test]
+    fn test_picker() {
+        let dictionary = HanjaDictionary::parse(SAMPLE);
+        let mut picker = HanjaPicker::open(&dictionary, "한").unwrap();
+        assert_eq!(picker.selected().hanja, "韓");
+        picker.prev();
+        assert_eq!(picker.selected().hanja, "漢");
+        picker.next();
+        assert_eq!(picker.selected().hanja, "韓");
+        assert_eq!(picker.numbered('2'), Some(1));
+        assert!(picker.numbered('0').is_none());
+        assert!(picker.numbered('3').is_none());
+    }
+
+    #[test]
+    fn test_bundled_dictionary_parses() {
+        let content = include_str!("../../assets/hanja.txt");
+        let dictionary = HanjaDictionary::parse(content);
+        assert_eq!(dictionary.lookup("훈민정음")[0].hanja, "訓民正音");
+        assert!(dictionary.lookup("한").len() > 1);
+    }
+}
@@ -0,0 +1,171 @@
+// src/services/improviser.rs
+//
+// Improvisation: a syllable-level n-gram model of everything typed so far
+// (plus optional corpus files) that makes up new lines in the same voice.
+// Each next syllable is drawn from what followed the last `order - 1`
+// syllables in training, backing off to shorter contexts when the long one
+// was never seen. Temperature flattens (> 1) or sharpens (< 1) the choice.
+
+use rand::Rng;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+// Pads the start of every line, so openings are learned as openings.
+const LINE_START: char = '\u{2}';
+
+// Lowest temperature used; 0 would divide by zero.
+const MIN_TEMPERATURE: f32 = 0.05;
+
+// BTreeMaps rather than HashMaps: the same seed must give the same line.
+#[derive(Debug, Clone)]
+pub struct NgramModel {
+    order: usize,
+    // context → next syllable (None ends the line) → count
+    counts: BTreeMap<Vec<char>, BTreeMap<Option<char>, usize>>,
+}
+
+impl NgramModel {
+    /// `order` counts the predicted syllable too: 3 looks two back.
+    pub fn new(order: usize) -> Self {
+        Self {
+            order: order.max(2),
+            counts: BTreeMap::new(),
+        }
+    }
+
+    /// Learn every line of a corpus file.
+    pub fn load_corpus(&mut self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        for line in content.lines() {
+            self.learn(line);
+        }
+        Ok(())
+    }
+
+    /// Learn one line.
+    pub fn learn(&mut self, line: &str) {
+        let line = line.trim();
+        if line.is_empty() {
+            return;
+        }
+        let mut padded = vec![LINE_START; self.order - 1];
+        let start = padded.len();
+        padded.extend(line.chars());
+
+        for at in start..=padded.len() {
+            let next = padded.get(at).copied();
+            for len in 1..self.order {
+                let context = padded[at - len..at].to_vec();
+                *self
+                    .counts
+                    .entry(context)
+                    .or_default()
+                    .entry(next)
+                    .or_default() += 1;
+            }
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.counts.is_empty()
+    }
+
+    /// Make up a line of at most `max_len` syllables. Empty if nothing has
+    /// been learned yet.
+    pub fn generate(&self, rng: &mut impl Rng, temperature: f32, max_len: usize) -> String {
+        let exponent = 1.0 / temperature.max(MIN_TEMPERATURE) as f64;
+        let mut text = vec![LINE_START; self.order - 1];
+        let start = text.len();
+
+        while text.len() - start < max_len {
+            let Some(followers) = self.followers(&text) else {
+                break;
+            };
+            let weights: Vec<(Option<char>, f64)> = followers
+                .iter()
+                .map(|(next, count)| (*next, (*count as f64).powf(exponent)))
+                .collect();
+            let total: f64 = weights.iter().map(|(_, weight)| weight).sum();
+
+            let mut pick = rng.gen_range(0.0..total);
+            let mut chosen = weights[weights.len() - 1].0;
+            for (next, weight) in &weights {
+                if pick < *weight {
+                    chosen = *next;
+                    break;
+                }
+                pick -= weight;
+            }
+            match chosen {
+                Some(ch) => text.push(ch),
+                None => break,
+            }
+        }
+        text[start..].iter().collect::<String>().trim().to_owned()
+    }
+
+    // What followed the longest seen ending of `text`.
+    fn followers(&self, text: &[char]) -> Option<&BTreeMap<Option<char>, usize>> {
+        (1..self.order)
+            .rev()
+            .filter(|&len| len <= text.len())
+            .find_map(|len| self.counts.get(&text[text.len() - len..]))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_unique_line_is_reproduced() {
+        let mut model = NgramModel::new(3);
+        assert!(model.is_empty());
+        assert_eq!(model.generate(&mut StdRng::seed_from_u64(0), 1.0, 40), "");
+
+        model.learn("동창이 밝았느냐");
+        let mut rng = StdRng::seed_from_u64(0);
+        assert_eq!(model.generate(&mut rng, 1.0, 40), "동창이 밝았느냐");
+        assert_eq!(model.generate(&mut rng, 1.0, 3), "동창이");
+    }
+
+    #[test]
+    fn test_same_seed_same_line() {
+        let mut model = NgramModel::new(2);
+        for line in [
+            "아리랑 아리랑 아라리요",
+            "아리랑 고개로 넘어간다",
+            "나를 버리고 가시는 님은",
+        ] {
+            model.learn(line);
+        }
+        let line = model.generate(&mut StdRng::seed_from_u64(42), 1.5, 30);
+        assert_eq!(
+            model.generate(&mut StdRng::seed_from_u64(42), 1.5, 30),
+            line
+        );
+        assert!(line.chars().count() <= 30);
+
+        // every step was seen in training
+        let training = "아리랑 아리랑 아라리요 아리랑 고개로 넘어간다 나를 버리고 가시는 님은";
+        let chars: Vec<char> = line.chars().collect();
+        for pair in chars.windows(2) {
+            let pair: String = pair.iter().collect();
+            assert!(training.contains(&pair), "{:?} in {:?}", pair, line);
+        }
+    }
+
+    #[test]
+    fn test_low_temperature_takes_the_common_path() {
+        let mut model = NgramModel::new(3);
+        for line in ["가나", "가나", "가나", "가다"] {
+            model.learn(line);
+        }
+        for seed in 0..20 {
+            let mut rng = StdRng::seed_from_u64(seed);
+            assert_eq!(model.generate(&mut rng, 0.1, 10), "가나");
+        }
+    }
+}
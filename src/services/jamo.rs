@@ -0,0 +1,127 @@
+// src/services/jamo.rs
+//
+// Tables and conversions between compatibility jamo (U+3131 block, what a
+// dubeolsik IME sends) and conjoining jamo (U+1100 block, which carry an
+// explicit choseong/jungseong/jongseong role).
+
+// Compatibility jamo in Unicode conjoining order for each role.
+pub const CHOSEONG: [char; 19] = [
+    'ㄱ', 'ㄲ', 'ㄴ', 'ㄷ', 'ㄸ', 'ㄹ', 'ㅁ', 'ㅂ', 'ㅃ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅉ', 'ㅊ', 'ㅋ',
+    'ㅌ', 'ㅍ', 'ㅎ',
+];
+pub const JUNGSEONG: [char; 21] = [
+    'ㅏ', 'ㅐ', 'ㅑ', 'ㅒ', 'ㅓ', 'ㅔ', 'ㅕ', 'ㅖ', 'ㅗ', 'ㅘ', 'ㅙ', 'ㅚ', 'ㅛ', 'ㅜ', 'ㅝ', 'ㅞ',
+    'ㅟ', 'ㅠ', 'ㅡ', 'ㅢ', 'ㅣ',
+];
+pub const JONGSEONG: [char; 27] = [
+    'ㄱ', 'ㄲ', 'ㄳ', 'ㄴ', 'ㄵ', 'ㄶ', 'ㄷ', 'ㄹ', 'ㄺ', 'ㄻ', 'ㄼ', 'ㄽ', 'ㄾ', 'ㄿ', 'ㅀ', 'ㅁ',
+    'ㅂ', 'ㅄ', 'ㅅ', 'ㅆ', 'ㅇ', 'ㅈ', 'ㅊ', 'ㅋ', 'ㅌ', 'ㅍ', 'ㅎ',
+];
+
+const CHOSEONG_BASE: u32 = 0x1100;
+const JUNGSEONG_BASE: u32 = 0x1161;
+const JONGSEONG_BASE: u32 = 0x11A8;
+const SYLLABLE_BASE: u32 = 0xAC00;
+const SYLLABLE_LAST: u32 = 0xD7A3;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum JamoRole {
+    Choseong,
+    Jungseong,
+    Jongseong,
+}
+
+/// Role of a modern conjoining jamo, None for anything else.
+pub fn conjoining_role(ch: char) -> Option<JamoRole> {
+    let code = ch as u32;
+    if (CHOSEONG_BASE..CHOSEONG_BASE + CHOSEONG.len() as u32).contains(&code) {
+        Some(JamoRole::Choseong)
+    } else if (JUNGSEONG_BASE..JUNGSEONG_BASE + JUNGSEONG.len() as u32).contains(&code) {
+        Some(JamoRole::Jungseong)
+    } else if (JONGSEONG_BASE..JONGSEONG_BASE + JONGSEONG.len() as u32).contains(&code) {
+        Some(JamoRole::Jongseong)
+    } else {
+        None
+    }
+}
+
+/// Conjoining jamo → compatibility jamo.
+pub fn to_compat(ch: char) -> Option<char> {
+    let code = ch as u32;
+    match conjoining_role(ch)? {
+        JamoRole::Choseong => Some(CHOSEONG[(code - CHOSEONG_BASE) as usize]),
+        JamoRole::Jungseong => Some(JUNGSEONG[(code - JUNGSEONG_BASE) as usize]),
+        JamoRole::Jongseong => Some(JONGSEONG[(code - JONGSEONG_BASE) as usize]),
+    }
+}
+
+/// Compatibility jamo → conjoining jamo in the given role.
+pub fn to_conjoining(ch: char, role: JamoRole) -> Option<char> {
+    let (table, base): (&[char], u32) = match role {
+        JamoRole::Choseong => (&CHOSEONG, CHOSEONG_BASE),
+        JamoRole::Jungseong => (&JUNGSEONG, JUNGSEONG_BASE),
+        JamoRole::Jongseong => (&JONGSEONG, JONGSEONG_BASE),
+    };
+    let index = table.iter().position(|c| *c == ch)?;
+    char::from_u32(base + index as u32)
+}
+
+pub fn is_syllable(ch: char) -> bool {
+    (SYLLABLE_BASE..=SYLLABLE_LAST).contains(&(ch as u32))
+}
+
+/// Compose a precomposed syllable from conjoining jamo.
+pub fn compose_conjoining(cho: char, jung: char, jong: Option<char>) -> Option<char> {
+    if conjoining_role(cho)? != JamoRole::Choseong || conjoining_role(jung)? != JamoRole::Jungseong
+    {
+        return None;
+    }
+    let l = cho as u32 - CHOSEONG_BASE;
+    let v = jung as u32 - JUNGSEONG_BASE;
+    let t = match jong {
+        Some(j) if conjoining_role(j)? == JamoRole::Jongseong => j as u32 - JONGSEONG_BASE + 1,
+        Some(_) => return None,
+        None => 0,
+    };
+    char::from_u32(SYLLABLE_BASE + (l * 21 + v) * 28 + t)
+}
+
+/// Split a precomposed syllable into conjoining jamo.
+pub fn decompose_conjoining(syllable: char) -> Option<(char, char, Option<char>)> {
+    if !is_syllable(syllable) {
+        return None;
+    }
+    let index = syllable as u32 - SYLLABLE_BASE;
+    let cho = char::from_u32(CHOSEONG_BASE + index / (21 * 28))?;
+    let jung = char::from_u32(JUNGSEONG_BASE + (index / 28) % 21)?;
+    let jong = match index % 28 {
+        0 => None,
+        t => char::from_u32(JONGSEONG_BASE + t - 1),
+    };
+    Some((cho, jung, jong))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_roundtrip_compat() {
+        assert_eq!(to_compat('\u{1100}'), Some('ㄱ'));
+        assert_eq!(to_compat('\u{11B0}'), Some('ㄺ'));
+        assert_eq!(to_conjoining('ㄺ', JamoRole::Jongseong), Some('\u{11B0}'));
+        assert_eq!(to_conjoining('ㄺ', JamoRole::Choseong), None);
+        assert_eq!(to_compat('ㄱ'), None);
+    }
+
+    #[test]
+    fn test_compose_decompose() {
+        let han = compose_conjoining('\u{1112}', '\u{1161}', Some('\u{11AB}'));
+        assert_eq!(han, Some('한'));
+        assert_eq!(
+            decompose_conjoining('한'),
+            Some(('\u{1112}', '\u{1161}', Some('\u{11AB}')))
+        );
+        assert_eq!(compose_conjoining('\u{1161}', '\u{1161}', None), None);
+    }
+}
@@ -0,0 +1,360 @@
+// src/services/keyboard_layout.rs
+//
+// Software Korean keyboard layouts.
+// Converts the Latin characters a plain US layout delivers into jamo
+// before they reach the composer, so no OS Korean IME is needed.
+//
+// Dubeolsik produces compatibility jamo and relies on the composer to work
+// out syllable boundaries. Sebeolsik has separate initial and final
+// consonant keys, so it produces conjoining jamo whose role is explicit.
+
+use super::jamo::{to_conjoining, JamoRole};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyboardLayout {
+    #[default]
+    Dubeolsik,
+    Sebeolsik390,
+    SebeolsikFinal,
+    // Revised Romanization typed as Latin; transliterated by the composer
+    Romaja,
+}
+
+impl KeyboardLayout {
+    pub fn map_char(&self, ch: char) -> Option<char> {
+        match self {
+            KeyboardLayout::Dubeolsik => dubeolsik_jamo(ch),
+            KeyboardLayout::Sebeolsik390 => {
+                let (role, jamo) = sebeolsik_390_key(ch)?;
+                to_conjoining(jamo, role)
+            }
+            KeyboardLayout::SebeolsikFinal => {
+                let (role, jamo) = sebeolsik_final_key(ch)?;
+                to_conjoining(jamo, role)
+            }
+            KeyboardLayout::Romaja => None,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            KeyboardLayout::Dubeolsik => "두벌식",
+            KeyboardLayout::Sebeolsik390 => "세벌식 390",
+            KeyboardLayout::SebeolsikFinal => "세벌식 최종",
+            KeyboardLayout::Romaja => "로마자",
+        }
+    }
+
+    pub fn next(&self) -> Self {
+        match self {
+            KeyboardLayout::Dubeolsik => KeyboardLayout::Sebeolsik390,
+            KeyboardLayout::Sebeolsik390 => KeyboardLayout::SebeolsikFinal,
+            KeyboardLayout::SebeolsikFinal => KeyboardLayout::Romaja,
+            KeyboardLayout::Romaja => KeyboardLayout::Dubeolsik,
+        }
+    }
+}
+
+// Holds the active layout and whether Hangeul input is switched on,
+// the way the 한/영 key does on a Korean keyboard.
+#[derive(Debug, Default, Clone)]
+pub struct KeyMapper {
+    layout: KeyboardLayout,
+    hangeul_on: bool,
+    old_hangeul: bool,
+}
+
+impl KeyMapper {
+    pub fn new(layout: KeyboardLayout, hangeul_on: bool) -> Self {
+        Self {
+            layout,
+            hangeul_on,
+            old_hangeul: false,
+        }
+    }
+
+    /// Map a received character through the layout when Hangeul is on.
+    /// Characters the layout doesn't cover pass through untouched.
+    pub fn map_char(&self, ch: char) -> char {
+        if !self.hangeul_on {
+            return ch;
+        }
+        if self.old_hangeul && self.layout == KeyboardLayout::Dubeolsik {
+            if let Some(letter) = dubeolsik_old_letter(ch) {
+                return letter;
+            }
+        }
+        self.layout.map_char(ch).unwrap_or(ch)
+    }
+
+    pub fn toggle(&mut self) {
+        self.hangeul_on = !self.hangeul_on;
+    }
+
+    pub fn is_hangeul_on(&self) -> bool {
+        self.hangeul_on
+    }
+
+    /// Switch Old Hangeul (옛한글) letters on or off; returns the new state.
+    pub fn toggle_old_hangeul(&mut self) -> bool {
+        self.old_hangeul = !self.old_hangeul;
+        self.old_hangeul
+    }
+
+    pub fn is_old_hangeul(&self) -> bool {
+        self.old_hangeul
+    }
+
+    /// Whether typed Latin should go to the composer as romaja.
+    pub fn is_romaja(&self) -> bool {
+        self.hangeul_on && self.layout == KeyboardLayout::Romaja
+    }
+
+    pub fn layout(&self) -> KeyboardLayout {
+        self.layout
+    }
+
+    pub fn cycle_layout(&mut self) {
+        self.layout = self.layout.next();
+    }
+
+    /// Prompt shown in front of the input line.
+    pub fn prompt(&self) -> String {
+        if self.hangeul_on && self.old_hangeul {
+            format!("옛({}) > ", self.layout.name())
+        } else if self.hangeul_on {
+            format!("한({}) > ", self.layout.name())
+        } else {
+            "A > ".to_owned()
+        }
+    }
+}
+
+/// Standard dubeolsik (KS X 5002) mapping.
+/// Shift only matters on the keys that carry a tense consonant or ㅒ/ㅖ;
+/// everywhere else an uppercase letter types the same jamo as lowercase.
+pub fn dubeolsik_jamo(ch: char) -> Option<char> {
+    let jamo = match ch {
+        'Q' => 'ㅃ',
+        'W' => 'ㅉ',
+        'E' => 'ㄸ',
+        'R' => 'ㄲ',
+        'T' => 'ㅆ',
+        'O' => 'ㅒ',
+        'P' => 'ㅖ',
+        _ => match ch.to_ascii_lowercase() {
+            'q' => 'ㅂ',
+            'w' => 'ㅈ',
+            'e' => 'ㄷ',
+            'r' => 'ㄱ',
+            't' => 'ㅅ',
+            'y' => 'ㅛ',
+            'u' => 'ㅕ',
+            'i' => 'ㅑ',
+            'o' => 'ㅐ',
+            'p' => 'ㅔ',
+            'a' => 'ㅁ',
+            's' => 'ㄴ',
+            'd' => 'ㅇ',
+            'f' => 'ㄹ',
+            'g' => 'ㅎ',
+            'h' => 'ㅗ',
+            'j' => 'ㅓ',
+            'k' => 'ㅏ',
+            'l' => 'ㅣ',
+            'z' => 'ㅋ',
+            'x' => 'ㅌ',
+            'c' => 'ㅊ',
+            'v' => 'ㅍ',
+            'b' => 'ㅠ',
+            'n' => 'ㅜ',
+            'm' => 'ㅡ',
+            _ => return None,
+        },
+    };
+    Some(jamo)
+}
+
+/// Archaic letters on shifted dubeolsik keys that type nothing of their
+/// own, next to the modern letter each one grew out of.
+fn dubeolsik_old_letter(ch: char) -> Option<char> {
+    let letter = match ch {
+        'A' => 'ㅿ', // beside ㅁ, like the ㅅ it softens
+        'D' => 'ㆁ', // ㅇ
+        'G' => 'ㆆ', // ㅎ
+        'K' => 'ㆍ', // ㅏ
+        _ => return None,
+    };
+    Some(letter)
+}
+
+/// Keys shared by the 390 and Final sebeolsik layouts (unshifted).
+fn sebeolsik_common_key(ch: char) -> Option<(JamoRole, char)> {
+    use JamoRole::*;
+    let key = match ch {
+        '1' => (Jongseong, 'ㅎ'),
+        '2' => (Jongseong, 'ㅆ'),
+        '3' => (Jongseong, 'ㅂ'),
+        '4' => (Jungseong, 'ㅛ'),
+        '5' => (Jungseong, 'ㅠ'),
+        '6' => (Jungseong, 'ㅑ'),
+        '7' => (Jungseong, 'ㅖ'),
+        '8' => (Jungseong, 'ㅢ'),
+        '9' => (Jungseong, 'ㅜ'),
+        '0' => (Choseong, 'ㅋ'),
+        'q' => (Jongseong, 'ㅅ'),
+        'w' => (Jongseong, 'ㄹ'),
+        'e' => (Jungseong, 'ㅕ'),
+        'r' => (Jungseong, 'ㅐ'),
+        't' => (Jungseong, 'ㅓ'),
+        'y' => (Choseong, 'ㄹ'),
+        'u' => (Choseong, 'ㄷ'),
+        'i' => (Choseong, 'ㅁ'),
+        'o' => (Choseong, 'ㅊ'),
+        'p' => (Choseong, 'ㅍ'),
+        'a' => (Jongseong, 'ㅇ'),
+        's' => (Jongseong, 'ㄴ'),
+        'd' => (Jungseong, 'ㅣ'),
+        'f' => (Jungseong, 'ㅏ'),
+        'g' => (Jungseong, 'ㅡ'),
+        'h' => (Choseong, 'ㄴ'),
+        'j' => (Choseong, 'ㅇ'),
+        'k' => (Choseong, 'ㄱ'),
+        'l' => (Choseong, 'ㅈ'),
+        ';' => (Choseong, 'ㅂ'),
+        '\'' => (Choseong, 'ㅌ'),
+        'z' => (Jongseong, 'ㅁ'),
+        'x' => (Jongseong, 'ㄱ'),
+        'c' => (Jungseong, 'ㅔ'),
+        'v' => (Jungseong, 'ㅗ'),
+        'b' => (Jungseong, 'ㅜ'),
+        'n' => (Choseong, 'ㅅ'),
+        'm' => (Choseong, 'ㅎ'),
+        '/' => (Jungseong, 'ㅗ'),
+        _ => return None,
+    };
+    Some(key)
+}
+
+/// Sebeolsik 390: shifted keys carry the less common finals and ㅒ.
+pub fn sebeolsik_390_key(ch: char) -> Option<(JamoRole, char)> {
+    use JamoRole::*;
+    let key = match ch {
+        '!' => (Jongseong, 'ㅈ'),
+        'Q' => (Jongseong, 'ㅍ'),
+        'W' => (Jongseong, 'ㅌ'),
+        'E' => (Jongseong, 'ㅋ'),
+        'R' => (Jungseong, 'ㅒ'),
+        'A' => (Jongseong, 'ㄷ'),
+        'S' => (Jongseong, 'ㄶ'),
+        'D' => (Jongseong, 'ㄺ'),
+        'F' => (Jongseong, 'ㄲ'),
+        'Z' => (Jongseong, 'ㅊ'),
+        'X' => (Jongseong, 'ㅄ'),
+        'C' => (Jongseong, 'ㄻ'),
+        'V' => (Jongseong, 'ㅀ'),
+        _ => return sebeolsik_common_key(ch),
+    };
+    Some(key)
+}
+
+/// Sebeolsik Final (최종): every compound final has its own shifted key.
+pub fn sebeolsik_final_key(ch: char) -> Option<(JamoRole, char)> {
+    use JamoRole::*;
+    let key = match ch {
+        '!' => (Jongseong, 'ㄲ'),
+        '@' => (Jongseong, 'ㄺ'),
+        '#' => (Jongseong, 'ㅈ'),
+        '$' => (Jongseong, 'ㄿ'),
+        '%' => (Jongseong, 'ㄾ'),
+        'Q' => (Jongseong, 'ㅍ'),
+        'W' => (Jongseong, 'ㅌ'),
+        'E' => (Jongseong, 'ㄵ'),
+        'R' => (Jongseong, 'ㅀ'),
+        'T' => (Jongseong, 'ㄽ'),
+        'A' => (Jongseong, 'ㄷ'),
+        'S' => (Jongseong, 'ㄶ'),
+        'D' => (Jongseong, 'ㄼ'),
+        'F' => (Jongseong, 'ㄻ'),
+        'G' => (Jungseong, 'ㅒ'),
+        'Z' => (Jongseong, 'ㅊ'),
+        'X' => (Jongseong, 'ㅄ'),
+        'C' => (Jongseong, 'ㅋ'),
+        'V' => (Jongseong, 'ㄳ'),
+        _ => return sebeolsik_common_key(ch),
+    };
+    Some(key)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::services::hangeul_composer::HangeulComposer;
+
+    #[test]
+    fn test_dubeolsik_mapping() {
+        assert_eq!(dubeolsik_jamo('r'), Some('ㄱ'));
+        assert_eq!(dubeolsik_jamo('R'), Some('ㄲ'));
+        assert_eq!(dubeolsik_jamo('k'), Some('ㅏ'));
+        assert_eq!(dubeolsik_jamo('K'), Some('ㅏ'));
+        assert_eq!(dubeolsik_jamo('P'), Some('ㅖ'));
+        assert_eq!(dubeolsik_jamo('1'), None);
+    }
+
+    #[test]
+    fn test_mapper_toggle() {
+        let mut mapper = KeyMapper::new(KeyboardLayout::Dubeolsik, false);
+        assert_eq!(mapper.map_char('r'), 'r');
+        mapper.toggle();
+        assert_eq!(mapper.map_char('r'), 'ㄱ');
+        assert_eq!(mapper.map_char(' '), ' ');
+    }
+
+    #[test]
+    fn test_old_hangeul_keys() {
+        let mut mapper = KeyMapper::new(KeyboardLayout::Dubeolsik, true);
+        assert_eq!(mapper.map_char('K'), 'ㅏ');
+        assert!(mapper.toggle_old_hangeul());
+        assert_eq!(mapper.map_char('K'), 'ㆍ');
+        assert_eq!(mapper.map_char('k'), 'ㅏ');
+        assert_eq!(mapper.prompt(), "옛(두벌식) > ");
+    }
+
+    #[test]
+    fn test_typing_through_composer() {
+        let mapper = KeyMapper::new(KeyboardLayout::Dubeolsik, true);
+        let mut composer = HangeulComposer::new();
+        for ch in "gnsalswjddmaTkTdma".chars() {
+            composer.push_char(mapper.map_char(ch));
+        }
+        assert_eq!(composer.display(), "훈민정음쌌음");
+    }
+
+    #[test]
+    fn test_sebeolsik_roles() {
+        let layout = KeyboardLayout::Sebeolsik390;
+        // ㅇ as initial (j) and as final (a) are distinct codepoints
+        assert_eq!(layout.map_char('j'), Some('\u{110B}'));
+        assert_eq!(layout.map_char('a'), Some('\u{11BC}'));
+        assert_eq!(layout.map_char('f'), Some('\u{1161}'));
+    }
+
+    #[test]
+    fn test_sebeolsik_no_resyllabification() {
+        // 각 + 아 typed as ㄱ ㅏ ㄱ(final) ㅇ ㅏ stays 각아
+        let mapper = KeyMapper::new(KeyboardLayout::SebeolsikFinal, true);
+        let mut composer = HangeulComposer::new();
+        for ch in "kfxjf".chars() {
+            composer.push_char(mapper.map_char(ch));
+        }
+        assert_eq!(composer.display(), "각아");
+
+        let mut composer = HangeulComposer::new();
+        for ch in "mbsjgz".chars() {
+            composer.push_char(mapper.map_char(ch));
+        }
+        assert_eq!(composer.display(), "훈음");
+    }
+}
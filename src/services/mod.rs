@@ -1 +1,18 @@
+pub mod autotypist;
+pub mod composer_event;
 pub mod hangeul_composer;
+pub mod hanja;
+pub mod improviser;
+pub mod jamo;
+pub mod keyboard_layout;
+pub mod normalization;
+pub mod old_hangeul;
+mod old_hangeul_tables;
+pub mod osc_receiver;
+pub mod osc_sender;
+pub mod predictor;
+pub mod pronunciation;
+pub mod romaja;
+pub mod romanization;
+pub mod sijo;
+pub mod syllable_analysis;
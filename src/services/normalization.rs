@@ -0,0 +1,61 @@
+// src/services/normalization.rs
+//
+// Unicode normalization at the edges of the composer.
+// Text that doesn't come from the keyboard (pastes, remote messages) may be
+// precomposed (NFC) or spelled out in conjoining jamo (NFD). It is brought
+// to NFC before composition so modern syllables arrive whole; finished
+// lines can be exported in whichever form the receiving end expects.
+
+use serde::Deserialize;
+use unicode_normalization::UnicodeNormalization;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum NormalForm {
+    // precomposed syllables: 한
+    #[default]
+    Nfc,
+    // conjoining jamo: ᄒ ᅡ ᆫ
+    Nfd,
+    // as NFC, with compatibility jamo (ㄱ) folded into conjoining ones
+    Nfkc,
+    Nfkd,
+}
+
+/// Bring any mix of compatibility jamo, conjoining jamo, NFD and NFC into
+/// the form the composer expects.
+pub fn canonicalize(text: &str) -> String {
+    text.nfc().collect()
+}
+
+/// Convert a committed line for output.
+pub fn to_normal_form(text: &str, form: NormalForm) -> String {
+    match form {
+        NormalForm::Nfc => text.nfc().collect(),
+        NormalForm::Nfd => text.nfd().collect(),
+        NormalForm::Nfkc => text.nfkc().collect(),
+        NormalForm::Nfkd => text.nfkd().collect(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const NFD_HANGEUL: &str = "\u{1112}\u{1161}\u{11AB}\u{1100}\u{1173}\u{11AF}";
+
+    #[test]
+    fn test_canonicalize() {
+        assert_eq!(canonicalize(NFD_HANGEUL), "한글");
+        assert_eq!(canonicalize("한글"), "한글");
+        // compatibility jamo are left for the composer to assemble
+        assert_eq!(canonicalize("ㅎㅏㄴ"), "ㅎㅏㄴ");
+    }
+
+    #[test]
+    fn test_export_forms() {
+        assert_eq!(to_normal_form("한글", NormalForm::Nfc), "한글");
+        assert_eq!(to_normal_form("한글", NormalForm::Nfd), NFD_HANGEUL);
+        assert_eq!(to_normal_form("ㄱ", NormalForm::Nfkd), "\u{1100}");
+    }
+}
@@ -0,0 +1,231 @@
+// src/services/old_hangeul.rs
+//
+// Old Hangeul (옛한글) syllables for the archaic composition mode.
+//
+// Modern syllables fit three slots of one jamo each, which is all
+// `collapse_to_syllable` handles. 15th-century text also uses ㆍ ㅿ ㆁ ㆆ
+// and consonant clusters such as ㅴ, so here every slot is a list of typed
+// letters that folds into a single conjoining jamo. Syllables that turn
+// out to be modern are emitted precomposed; everything else is emitted as
+// a conjoining-jamo sequence for a suitable font to render.
+
+use super::jamo::{compose_conjoining, JamoRole};
+use super::old_hangeul_tables::{CLUSTERS, LETTERS};
+
+const CHOSEONG_FILLER: char = '\u{115F}';
+const JUNGSEONG_FILLER: char = '\u{1160}';
+
+/// Whether `ch` is a compatibility letter the archaic composer can use.
+pub fn is_old_letter(ch: char) -> bool {
+    LETTERS.iter().any(|(letter, _, _)| *letter == ch)
+}
+
+pub fn is_vowel_letter(ch: char) -> bool {
+    letter_in_role(ch, JamoRole::Jungseong).is_some()
+}
+
+fn letter_in_role(ch: char, role: JamoRole) -> Option<char> {
+    LETTERS
+        .iter()
+        .find(|(letter, r, _)| *letter == ch && *r == role)
+        .map(|(_, _, conjoining)| *conjoining)
+}
+
+/// Fold a run of typed letters into one conjoining jamo.
+fn cluster(role: JamoRole, letters: &[char]) -> Option<char> {
+    let (first, rest) = letters.split_first()?;
+    rest.iter()
+        .try_fold(letter_in_role(*first, role)?, |so_far, next| {
+            CLUSTERS
+                .iter()
+                .find(|(r, prefix, letter, _)| *r == role && *prefix == so_far && letter == next)
+                .map(|(_, _, _, longer)| *longer)
+        })
+}
+
+// One syllable as the letters typed into each slot.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct OldSyllable {
+    initial: Vec<char>,
+    medial: Vec<char>,
+    last: Vec<char>,
+}
+
+impl OldSyllable {
+    /// The typed letters in order.
+    pub fn letters(&self) -> Vec<char> {
+        let mut letters = self.initial.clone();
+        letters.extend(&self.medial);
+        letters.extend(&self.last);
+        letters
+    }
+
+    /// Has an initial and a medial that each fold into one jamo.
+    pub fn is_complete(&self) -> bool {
+        cluster(JamoRole::Choseong, &self.initial).is_some()
+            && cluster(JamoRole::Jungseong, &self.medial).is_some()
+            && (self.last.is_empty() || cluster(JamoRole::Jongseong, &self.last).is_some())
+    }
+
+    pub fn has_final(&self) -> bool {
+        !self.last.is_empty()
+    }
+
+    /// Precomposed when modern, otherwise choseong + jungseong (+ jongseong)
+    /// with fillers for missing slots. Lone letters stay compatibility jamo.
+    pub fn render(&self) -> String {
+        let l = cluster(JamoRole::Choseong, &self.initial);
+        let v = cluster(JamoRole::Jungseong, &self.medial);
+        let t = cluster(JamoRole::Jongseong, &self.last);
+
+        let letters = self.letters();
+        if letters.len() == 1 {
+            return letters.into_iter().collect();
+        }
+
+        let valid = (self.initial.is_empty() || l.is_some())
+            && (self.medial.is_empty() || v.is_some())
+            && (self.last.is_empty() || t.is_some());
+        if !valid {
+            return letters.into_iter().collect();
+        }
+
+        if let (Some(l), Some(v)) = (l, v) {
+            if let Some(modern) = compose_conjoining(l, v, t) {
+                return modern.to_string();
+            }
+        }
+
+        let mut out = String::new();
+        out.push(l.unwrap_or(CHOSEONG_FILLER));
+        out.push(v.unwrap_or(JUNGSEONG_FILLER));
+        out.extend(t);
+        out
+    }
+
+    /// Add a letter to this syllable if it still fits.
+    fn push(&mut self, letter: char) -> bool {
+        if is_vowel_letter(letter) {
+            // a vowel after a final starts a new syllable
+            if !self.last.is_empty() {
+                return false;
+            }
+            return try_extend(&mut self.medial, JamoRole::Jungseong, letter);
+        }
+
+        if self.medial.is_empty() {
+            if !self.last.is_empty() {
+                return false;
+            }
+            return try_extend(&mut self.initial, JamoRole::Choseong, letter);
+        }
+        try_extend(&mut self.last, JamoRole::Jongseong, letter)
+    }
+
+    /// A vowel arriving after a final takes the final's last letter as its
+    /// initial, as in modern typing (ᄀᆞᆯ + ㆍ → ᄀᆞ / ᄅᆞ).
+    fn release_final(&mut self) -> Option<char> {
+        let (&moved, rest) = self.last.split_last()?;
+        let movable = letter_in_role(moved, JamoRole::Choseong).is_some()
+            && (rest.is_empty() || cluster(JamoRole::Jongseong, rest).is_some());
+        if !movable {
+            return None;
+        }
+        self.last.pop();
+        Some(moved)
+    }
+}
+
+fn try_extend(slot: &mut Vec<char>, role: JamoRole, letter: char) -> bool {
+    slot.push(letter);
+    if cluster(role, slot).is_some() {
+        return true;
+    }
+    slot.pop();
+    false
+}
+
+/// Split typed letters into syllables. All but the last are finished.
+pub fn split_syllables(letters: &[char]) -> Vec<OldSyllable> {
+    let mut syllables = Vec::new();
+    let mut current = OldSyllable::default();
+
+    for &letter in letters {
+        if current.push(letter) {
+            continue;
+        }
+
+        let mut next = OldSyllable::default();
+        if is_vowel_letter(letter) {
+            if let Some(moved) = current.release_final() {
+                next.initial.push(moved);
+            }
+            next.medial.push(letter);
+        } else if letter_in_role(letter, JamoRole::Choseong).is_some() {
+            next.initial.push(letter);
+        } else {
+            // only valid as a final: leave it on its own
+            next.last.push(letter);
+        }
+        let finished = std::mem::replace(&mut current, next);
+        if finished != OldSyllable::default() {
+            syllables.push(finished);
+        }
+    }
+    if current != OldSyllable::default() {
+        syllables.push(current);
+    }
+    syllables
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn render_all(letters: &str) -> String {
+        let letters: Vec<char> = letters.chars().collect();
+        split_syllables(&letters)
+            .iter()
+            .map(OldSyllable::render)
+            .collect()
+    }
+
+    #[test]
+    fn test_modern_stays_precomposed() {
+        assert_eq!(render_all("ㅎㅏㄴㄱㅡㄹ"), "한글");
+        assert_eq!(render_all("ㄷㅏㄹㄱㅇㅣ"), "닭이");
+    }
+
+    #[test]
+    fn test_arae_a() {
+        assert_eq!(render_all("ㄱㆍㄹ"), "\u{1100}\u{119E}\u{11AF}");
+        // the final moves over to the next vowel
+        assert_eq!(render_all("ㄴㆍㄹㅏ"), "\u{1102}\u{119E}라");
+        // ㆍ + ㅣ → ㆎ
+        assert_eq!(render_all("ㅁㆍㅣ"), "\u{1106}\u{11A1}");
+    }
+
+    #[test]
+    fn test_hunminjeongeum() {
+        assert_eq!(
+            render_all("ㅎㅜㄴㅁㅣㄴㅈㅕㆁㆆㅡㅁ"),
+            "훈민\u{110C}\u{1167}\u{11F0}\u{1159}\u{1173}\u{11B7}"
+        );
+    }
+
+    #[test]
+    fn test_initial_clusters() {
+        // ㅂ ㅅ ㄱ typed in turn become one choseong ㅴ
+        assert_eq!(render_all("ㅂㅅㄱㅏ"), "\u{1122}\u{1161}");
+        // as does the compatibility letter itself
+        assert_eq!(render_all("ㅴㅏ"), "\u{1122}\u{1161}");
+        // light ㅂ from ㅂ + ㅇ
+        assert_eq!(render_all("ㅂㅇㅜ"), "\u{112B}\u{116E}");
+    }
+
+    #[test]
+    fn test_bansiot() {
+        assert_eq!(render_all("ㅿㅏ"), "\u{1140}\u{1161}");
+        assert_eq!(render_all("ㅅㅏㅿ"), "\u{1109}\u{1161}\u{11EB}");
+    }
+}
@@ -0,0 +1,442 @@
+// src/services/old_hangeul_tables.rs
+//
+// Letter and cluster tables for Old Hangeul composition.
+// Generated from the Unicode character names of the Hangul Jamo
+// (U+1100), Jamo Extended-A (U+A960) and Jamo Extended-B (U+D7B0) blocks:
+// a cluster such as CHOSEONG PIEUP-SIOS-KIYEOK is reachable by typing
+// ㅂ, ㅅ, ㄱ in turn, and KAPYEOUN ("light") letters by adding ㅇ.
+
+use super::jamo::JamoRole::{self, *};
+
+// (compatibility letter, role, conjoining jamo)
+pub const LETTERS: [(char, JamoRole, char); 140] = [
+    ('ㄱ', Choseong, '\u{1100}'),  // CHOSEONG KIYEOK
+    ('ㄱ', Jongseong, '\u{11A8}'), // JONGSEONG KIYEOK
+    ('ㄲ', Choseong, '\u{1101}'),  // CHOSEONG SSANGKIYEOK
+    ('ㄲ', Jongseong, '\u{11A9}'), // JONGSEONG SSANGKIYEOK
+    ('ㄳ', Jongseong, '\u{11AA}'), // JONGSEONG KIYEOK-SIOS
+    ('ㄴ', Choseong, '\u{1102}'),  // CHOSEONG NIEUN
+    ('ㄴ', Jongseong, '\u{11AB}'), // JONGSEONG NIEUN
+    ('ㄵ', Choseong, '\u{115C}'),  // CHOSEONG NIEUN-CIEUC
+    ('ㄵ', Jongseong, '\u{11AC}'), // JONGSEONG NIEUN-CIEUC
+    ('ㄶ', Choseong, '\u{115D}'),  // CHOSEONG NIEUN-HIEUH
+    ('ㄶ', Jongseong, '\u{11AD}'), // JONGSEONG NIEUN-HIEUH
+    ('ㄷ', Choseong, '\u{1103}'),  // CHOSEONG TIKEUT
+    ('ㄷ', Jongseong, '\u{11AE}'), // JONGSEONG TIKEUT
+    ('ㄸ', Choseong, '\u{1104}'),  // CHOSEONG SSANGTIKEUT
+    ('ㄸ', Jongseong, '\u{D7CD}'), // JONGSEONG SSANGTIKEUT
+    ('ㄹ', Choseong, '\u{1105}'),  // CHOSEONG RIEUL
+    ('ㄹ', Jongseong, '\u{11AF}'), // JONGSEONG RIEUL
+    ('ㄺ', Choseong, '\u{A964}'),  // CHOSEONG RIEUL-KIYEOK
+    ('ㄺ', Jongseong, '\u{11B0}'), // JONGSEONG RIEUL-KIYEOK
+    ('ㄻ', Choseong, '\u{A968}'),  // CHOSEONG RIEUL-MIEUM
+    ('ㄻ', Jongseong, '\u{11B1}'), // JONGSEONG RIEUL-MIEUM
+    ('ㄼ', Choseong, '\u{A969}'),  // CHOSEONG RIEUL-PIEUP
+    ('ㄼ', Jongseong, '\u{11B2}'), // JONGSEONG RIEUL-PIEUP
+    ('ㄽ', Choseong, '\u{A96C}'),  // CHOSEONG RIEUL-SIOS
+    ('ㄽ', Jongseong, '\u{11B3}'), // JONGSEONG RIEUL-SIOS
+    ('ㄾ', Jongseong, '\u{11B4}'), // JONGSEONG RIEUL-THIEUTH
+    ('ㄿ', Jongseong, '\u{11B5}'), // JONGSEONG RIEUL-PHIEUPH
+    ('ㅀ', Choseong, '\u{111A}'),  // CHOSEONG RIEUL-HIEUH
+    ('ㅀ', Jongseong, '\u{11B6}'), // JONGSEONG RIEUL-HIEUH
+    ('ㅁ', Choseong, '\u{1106}'),  // CHOSEONG MIEUM
+    ('ㅁ', Jongseong, '\u{11B7}'), // JONGSEONG MIEUM
+    ('ㅂ', Choseong, '\u{1107}'),  // CHOSEONG PIEUP
+    ('ㅂ', Jongseong, '\u{11B8}'), // JONGSEONG PIEUP
+    ('ㅃ', Choseong, '\u{1108}'),  // CHOSEONG SSANGPIEUP
+    ('ㅃ', Jongseong, '\u{D7E6}'), // JONGSEONG SSANGPIEUP
+    ('ㅄ', Choseong, '\u{1121}'),  // CHOSEONG PIEUP-SIOS
+    ('ㅄ', Jongseong, '\u{11B9}'), // JONGSEONG PIEUP-SIOS
+    ('ㅅ', Choseong, '\u{1109}'),  // CHOSEONG SIOS
+    ('ㅅ', Jongseong, '\u{11BA}'), // JONGSEONG SIOS
+    ('ㅆ', Choseong, '\u{110A}'),  // CHOSEONG SSANGSIOS
+    ('ㅆ', Jongseong, '\u{11BB}'), // JONGSEONG SSANGSIOS
+    ('ㅇ', Choseong, '\u{110B}'),  // CHOSEONG IEUNG
+    ('ㅇ', Jongseong, '\u{11BC}'), // JONGSEONG IEUNG
+    ('ㅈ', Choseong, '\u{110C}'),  // CHOSEONG CIEUC
+    ('ㅈ', Jongseong, '\u{11BD}'), // JONGSEONG CIEUC
+    ('ㅉ', Choseong, '\u{110D}'),  // CHOSEONG SSANGCIEUC
+    ('ㅉ', Jongseong, '\u{D7F9}'), // JONGSEONG SSANGCIEUC
+    ('ㅊ', Choseong, '\u{110E}'),  // CHOSEONG CHIEUCH
+    ('ㅊ', Jongseong, '\u{11BE}'), // JONGSEONG CHIEUCH
+    ('ㅋ', Choseong, '\u{110F}'),  // CHOSEONG KHIEUKH
+    ('ㅋ', Jongseong, '\u{11BF}'), // JONGSEONG KHIEUKH
+    ('ㅌ', Choseong, '\u{1110}'),  // CHOSEONG THIEUTH
+    ('ㅌ', Jongseong, '\u{11C0}'), // JONGSEONG THIEUTH
+    ('ㅍ', Choseong, '\u{1111}'),  // CHOSEONG PHIEUPH
+    ('ㅍ', Jongseong, '\u{11C1}'), // JONGSEONG PHIEUPH
+    ('ㅎ', Choseong, '\u{1112}'),  // CHOSEONG HIEUH
+    ('ㅎ', Jongseong, '\u{11C2}'), // JONGSEONG HIEUH
+    ('ㅏ', Jungseong, '\u{1161}'), // JUNGSEONG A
+    ('ㅐ', Jungseong, '\u{1162}'), // JUNGSEONG AE
+    ('ㅑ', Jungseong, '\u{1163}'), // JUNGSEONG YA
+    ('ㅒ', Jungseong, '\u{1164}'), // JUNGSEONG YAE
+    ('ㅓ', Jungseong, '\u{1165}'), // JUNGSEONG EO
+    ('ㅔ', Jungseong, '\u{1166}'), // JUNGSEONG E
+    ('ㅕ', Jungseong, '\u{1167}'), // JUNGSEONG YEO
+    ('ㅖ', Jungseong, '\u{1168}'), // JUNGSEONG YE
+    ('ㅗ', Jungseong, '\u{1169}'), // JUNGSEONG O
+    ('ㅘ', Jungseong, '\u{116A}'), // JUNGSEONG WA
+    ('ㅙ', Jungseong, '\u{116B}'), // JUNGSEONG WAE
+    ('ㅚ', Jungseong, '\u{116C}'), // JUNGSEONG OE
+    ('ㅛ', Jungseong, '\u{116D}'), // JUNGSEONG YO
+    ('ㅜ', Jungseong, '\u{116E}'), // JUNGSEONG U
+    ('ㅝ', Jungseong, '\u{116F}'), // JUNGSEONG WEO
+    ('ㅞ', Jungseong, '\u{1170}'), // JUNGSEONG WE
+    ('ㅟ', Jungseong, '\u{1171}'), // JUNGSEONG WI
+    ('ㅠ', Jungseong, '\u{1172}'), // JUNGSEONG YU
+    ('ㅡ', Jungseong, '\u{1173}'), // JUNGSEONG EU
+    ('ㅢ', Jungseong, '\u{1174}'), // JUNGSEONG YI
+    ('ㅣ', Jungseong, '\u{1175}'), // JUNGSEONG I
+    ('ㅥ', Choseong, '\u{1114}'),  // CHOSEONG SSANGNIEUN
+    ('ㅥ', Jongseong, '\u{11FF}'), // JONGSEONG SSANGNIEUN
+    ('ㅦ', Choseong, '\u{1115}'),  // CHOSEONG NIEUN-TIKEUT
+    ('ㅦ', Jongseong, '\u{11C6}'), // JONGSEONG NIEUN-TIKEUT
+    ('ㅧ', Choseong, '\u{115B}'),  // CHOSEONG NIEUN-SIOS
+    ('ㅧ', Jongseong, '\u{11C7}'), // JONGSEONG NIEUN-SIOS
+    ('ㅨ', Jongseong, '\u{11C8}'), // JONGSEONG NIEUN-PANSIOS
+    ('ㅩ', Jongseong, '\u{11CC}'), // JONGSEONG RIEUL-KIYEOK-SIOS
+    ('ㅪ', Choseong, '\u{A966}'),  // CHOSEONG RIEUL-TIKEUT
+    ('ㅪ', Jongseong, '\u{11CE}'), // JONGSEONG RIEUL-TIKEUT
+    ('ㅫ', Jongseong, '\u{11D3}'), // JONGSEONG RIEUL-PIEUP-SIOS
+    ('ㅬ', Jongseong, '\u{11D7}'), // JONGSEONG RIEUL-PANSIOS
+    ('ㅭ', Jongseong, '\u{11D9}'), // JONGSEONG RIEUL-YEORINHIEUH
+    ('ㅮ', Choseong, '\u{111C}'),  // CHOSEONG MIEUM-PIEUP
+    ('ㅮ', Jongseong, '\u{11DC}'), // JONGSEONG MIEUM-PIEUP
+    ('ㅯ', Choseong, '\u{A971}'),  // CHOSEONG MIEUM-SIOS
+    ('ㅯ', Jongseong, '\u{11DD}'), // JONGSEONG MIEUM-SIOS
+    ('ㅰ', Jongseong, '\u{11DF}'), // JONGSEONG MIEUM-PANSIOS
+    ('ㅱ', Choseong, '\u{111D}'),  // CHOSEONG KAPYEOUNMIEUM
+    ('ㅱ', Jongseong, '\u{11E2}'), // JONGSEONG KAPYEOUNMIEUM
+    ('ㅲ', Choseong, '\u{111E}'),  // CHOSEONG PIEUP-KIYEOK
+    ('ㅳ', Choseong, '\u{1120}'),  // CHOSEONG PIEUP-TIKEUT
+    ('ㅳ', Jongseong, '\u{D7E3}'), // JONGSEONG PIEUP-TIKEUT
+    ('ㅴ', Choseong, '\u{1122}'),  // CHOSEONG PIEUP-SIOS-KIYEOK
+    ('ㅵ', Choseong, '\u{1123}'),  // CHOSEONG PIEUP-SIOS-TIKEUT
+    ('ㅵ', Jongseong, '\u{D7E7}'), // JONGSEONG PIEUP-SIOS-TIKEUT
+    ('ㅶ', Choseong, '\u{1127}'),  // CHOSEONG PIEUP-CIEUC
+    ('ㅶ', Jongseong, '\u{D7E8}'), // JONGSEONG PIEUP-CIEUC
+    ('ㅷ', Choseong, '\u{1129}'),  // CHOSEONG PIEUP-THIEUTH
+    ('ㅸ', Choseong, '\u{112B}'),  // CHOSEONG KAPYEOUNPIEUP
+    ('ㅸ', Jongseong, '\u{11E6}'), // JONGSEONG KAPYEOUNPIEUP
+    ('ㅹ', Choseong, '\u{112C}'),  // CHOSEONG KAPYEOUNSSANGPIEUP
+    ('ㅺ', Choseong, '\u{112D}'),  // CHOSEONG SIOS-KIYEOK
+    ('ㅺ', Jongseong, '\u{11E7}'), // JONGSEONG SIOS-KIYEOK
+    ('ㅻ', Choseong, '\u{112E}'),  // CHOSEONG SIOS-NIEUN
+    ('ㅼ', Choseong, '\u{112F}'),  // CHOSEONG SIOS-TIKEUT
+    ('ㅼ', Jongseong, '\u{11E8}'), // JONGSEONG SIOS-TIKEUT
+    ('ㅽ', Choseong, '\u{1132}'),  // CHOSEONG SIOS-PIEUP
+    ('ㅽ', Jongseong, '\u{11EA}'), // JONGSEONG SIOS-PIEUP
+    ('ㅾ', Choseong, '\u{1136}'),  // CHOSEONG SIOS-CIEUC
+    ('ㅾ', Jongseong, '\u{D7EF}'), // JONGSEONG SIOS-CIEUC
+    ('ㅿ', Choseong, '\u{1140}'),  // CHOSEONG PANSIOS
+    ('ㅿ', Jongseong, '\u{11EB}'), // JONGSEONG PANSIOS
+    ('ㆀ', Choseong, '\u{1147}'),  // CHOSEONG SSANGIEUNG
+    ('ㆀ', Jongseong, '\u{11EE}'), // JONGSEONG SSANGIEUNG
+    ('ㆁ', Choseong, '\u{114C}'),  // CHOSEONG YESIEUNG
+    ('ㆁ', Jongseong, '\u{11F0}'), // JONGSEONG YESIEUNG
+    ('ㆂ', Jongseong, '\u{11F1}'), // JONGSEONG YESIEUNG-SIOS
+    ('ㆃ', Jongseong, '\u{11F2}'), // JONGSEONG YESIEUNG-PANSIOS
+    ('ㆄ', Choseong, '\u{1157}'),  // CHOSEONG KAPYEOUNPHIEUPH
+    ('ㆄ', Jongseong, '\u{11F4}'), // JONGSEONG KAPYEOUNPHIEUPH
+    ('ㆅ', Choseong, '\u{1158}'),  // CHOSEONG SSANGHIEUH
+    ('ㆆ', Choseong, '\u{1159}'),  // CHOSEONG YEORINHIEUH
+    ('ㆆ', Jongseong, '\u{11F9}'), // JONGSEONG YEORINHIEUH
+    ('ㆇ', Jungseong, '\u{1184}'), // JUNGSEONG YO-YA
+    ('ㆈ', Jungseong, '\u{1185}'), // JUNGSEONG YO-YAE
+    ('ㆉ', Jungseong, '\u{1188}'), // JUNGSEONG YO-I
+    ('ㆊ', Jungseong, '\u{1191}'), // JUNGSEONG YU-YEO
+    ('ㆋ', Jungseong, '\u{1192}'), // JUNGSEONG YU-YE
+    ('ㆌ', Jungseong, '\u{1194}'), // JUNGSEONG YU-I
+    ('ㆍ', Jungseong, '\u{119E}'), // JUNGSEONG ARAEA
+    ('ㆎ', Jungseong, '\u{11A1}'), // JUNGSEONG ARAEA-I
+];
+
+// (role, cluster so far, next letter, longer cluster)
+pub const CLUSTERS: [(JamoRole, char, char, char); 285] = [
+    (Choseong, '\u{1100}', 'ㄷ', '\u{115A}'), // CHOSEONG KIYEOK-TIKEUT
+    (Choseong, '\u{1102}', 'ㄱ', '\u{1113}'), // CHOSEONG NIEUN-KIYEOK
+    (Choseong, '\u{1102}', 'ㄷ', '\u{1115}'), // CHOSEONG NIEUN-TIKEUT
+    (Choseong, '\u{1102}', 'ㅂ', '\u{1116}'), // CHOSEONG NIEUN-PIEUP
+    (Choseong, '\u{1102}', 'ㅅ', '\u{115B}'), // CHOSEONG NIEUN-SIOS
+    (Choseong, '\u{1102}', 'ㅈ', '\u{115C}'), // CHOSEONG NIEUN-CIEUC
+    (Choseong, '\u{1102}', 'ㅎ', '\u{115D}'), // CHOSEONG NIEUN-HIEUH
+    (Choseong, '\u{1103}', 'ㄱ', '\u{1117}'), // CHOSEONG TIKEUT-KIYEOK
+    (Choseong, '\u{1103}', 'ㄹ', '\u{115E}'), // CHOSEONG TIKEUT-RIEUL
+    (Choseong, '\u{1103}', 'ㅁ', '\u{A960}'), // CHOSEONG TIKEUT-MIEUM
+    (Choseong, '\u{1103}', 'ㅂ', '\u{A961}'), // CHOSEONG TIKEUT-PIEUP
+    (Choseong, '\u{1103}', 'ㅅ', '\u{A962}'), // CHOSEONG TIKEUT-SIOS
+    (Choseong, '\u{1103}', 'ㅈ', '\u{A963}'), // CHOSEONG TIKEUT-CIEUC
+    (Choseong, '\u{1105}', 'ㄱ', '\u{A964}'), // CHOSEONG RIEUL-KIYEOK
+    (Choseong, '\u{1105}', 'ㄲ', '\u{A965}'), // CHOSEONG RIEUL-SSANGKIYEOK
+    (Choseong, '\u{1105}', 'ㄴ', '\u{1118}'), // CHOSEONG RIEUL-NIEUN
+    (Choseong, '\u{1105}', 'ㄷ', '\u{A966}'), // CHOSEONG RIEUL-TIKEUT
+    (Choseong, '\u{1105}', 'ㄸ', '\u{A967}'), // CHOSEONG RIEUL-SSANGTIKEUT
+    (Choseong, '\u{1105}', 'ㄹ', '\u{1119}'), // CHOSEONG SSANGRIEUL
+    (Choseong, '\u{1105}', 'ㅁ', '\u{A968}'), // CHOSEONG RIEUL-MIEUM
+    (Choseong, '\u{1105}', 'ㅂ', '\u{A969}'), // CHOSEONG RIEUL-PIEUP
+    (Choseong, '\u{1105}', 'ㅃ', '\u{A96A}'), // CHOSEONG RIEUL-SSANGPIEUP
+    (Choseong, '\u{1105}', 'ㅅ', '\u{A96C}'), // CHOSEONG RIEUL-SIOS
+    (Choseong, '\u{1105}', 'ㅇ', '\u{111B}'), // CHOSEONG KAPYEOUNRIEUL
+    (Choseong, '\u{1105}', 'ㅈ', '\u{A96D}'), // CHOSEONG RIEUL-CIEUC
+    (Choseong, '\u{1105}', 'ㅋ', '\u{A96E}'), // CHOSEONG RIEUL-KHIEUKH
+    (Choseong, '\u{1105}', 'ㅎ', '\u{111A}'), // CHOSEONG RIEUL-HIEUH
+    (Choseong, '\u{1105}', 'ㅸ', '\u{A96B}'), // CHOSEONG RIEUL-KAPYEOUNPIEUP
+    (Choseong, '\u{1106}', 'ㄱ', '\u{A96F}'), // CHOSEONG MIEUM-KIYEOK
+    (Choseong, '\u{1106}', 'ㄷ', '\u{A970}'), // CHOSEONG MIEUM-TIKEUT
+    (Choseong, '\u{1106}', 'ㅂ', '\u{111C}'), // CHOSEONG MIEUM-PIEUP
+    (Choseong, '\u{1106}', 'ㅅ', '\u{A971}'), // CHOSEONG MIEUM-SIOS
+    (Choseong, '\u{1106}', 'ㅇ', '\u{111D}'), // CHOSEONG KAPYEOUNMIEUM
+    (Choseong, '\u{1107}', 'ㄱ', '\u{111E}'), // CHOSEONG PIEUP-KIYEOK
+    (Choseong, '\u{1107}', 'ㄴ', '\u{111F}'), // CHOSEONG PIEUP-NIEUN
+    (Choseong, '\u{1107}', 'ㄷ', '\u{1120}'), // CHOSEONG PIEUP-TIKEUT
+    (Choseong, '\u{1107}', 'ㅅ', '\u{1121}'), // CHOSEONG PIEUP-SIOS
+    (Choseong, '\u{1107}', 'ㅆ', '\u{1125}'), // CHOSEONG PIEUP-SSANGSIOS
+    (Choseong, '\u{1107}', 'ㅇ', '\u{112B}'), // CHOSEONG KAPYEOUNPIEUP
+    (Choseong, '\u{1107}', 'ㅈ', '\u{1127}'), // CHOSEONG PIEUP-CIEUC
+    (Choseong, '\u{1107}', 'ㅊ', '\u{1128}'), // CHOSEONG PIEUP-CHIEUCH
+    (Choseong, '\u{1107}', 'ㅋ', '\u{A973}'), // CHOSEONG PIEUP-KHIEUKH
+    (Choseong, '\u{1107}', 'ㅌ', '\u{1129}'), // CHOSEONG PIEUP-THIEUTH
+    (Choseong, '\u{1107}', 'ㅍ', '\u{112A}'), // CHOSEONG PIEUP-PHIEUPH
+    (Choseong, '\u{1107}', 'ㅎ', '\u{A974}'), // CHOSEONG PIEUP-HIEUH
+    (Choseong, '\u{1108}', 'ㅇ', '\u{112C}'), // CHOSEONG KAPYEOUNSSANGPIEUP
+    (Choseong, '\u{1109}', 'ㄱ', '\u{112D}'), // CHOSEONG SIOS-KIYEOK
+    (Choseong, '\u{1109}', 'ㄴ', '\u{112E}'), // CHOSEONG SIOS-NIEUN
+    (Choseong, '\u{1109}', 'ㄷ', '\u{112F}'), // CHOSEONG SIOS-TIKEUT
+    (Choseong, '\u{1109}', 'ㄹ', '\u{1130}'), // CHOSEONG SIOS-RIEUL
+    (Choseong, '\u{1109}', 'ㅁ', '\u{1131}'), // CHOSEONG SIOS-MIEUM
+    (Choseong, '\u{1109}', 'ㅂ', '\u{1132}'), // CHOSEONG SIOS-PIEUP
+    (Choseong, '\u{1109}', 'ㅆ', '\u{1134}'), // CHOSEONG SIOS-SSANGSIOS
+    (Choseong, '\u{1109}', 'ㅇ', '\u{1135}'), // CHOSEONG SIOS-IEUNG
+    (Choseong, '\u{1109}', 'ㅈ', '\u{1136}'), // CHOSEONG SIOS-CIEUC
+    (Choseong, '\u{1109}', 'ㅊ', '\u{1137}'), // CHOSEONG SIOS-CHIEUCH
+    (Choseong, '\u{1109}', 'ㅋ', '\u{1138}'), // CHOSEONG SIOS-KHIEUKH
+    (Choseong, '\u{1109}', 'ㅌ', '\u{1139}'), // CHOSEONG SIOS-THIEUTH
+    (Choseong, '\u{1109}', 'ㅍ', '\u{113A}'), // CHOSEONG SIOS-PHIEUPH
+    (Choseong, '\u{1109}', 'ㅎ', '\u{113B}'), // CHOSEONG SIOS-HIEUH
+    (Choseong, '\u{110A}', 'ㅂ', '\u{A975}'), // CHOSEONG SSANGSIOS-PIEUP
+    (Choseong, '\u{110B}', 'ㄱ', '\u{1141}'), // CHOSEONG IEUNG-KIYEOK
+    (Choseong, '\u{110B}', 'ㄷ', '\u{1142}'), // CHOSEONG IEUNG-TIKEUT
+    (Choseong, '\u{110B}', 'ㄹ', '\u{A976}'), // CHOSEONG IEUNG-RIEUL
+    (Choseong, '\u{110B}', 'ㅁ', '\u{1143}'), // CHOSEONG IEUNG-MIEUM
+    (Choseong, '\u{110B}', 'ㅂ', '\u{1144}'), // CHOSEONG IEUNG-PIEUP
+    (Choseong, '\u{110B}', 'ㅅ', '\u{1145}'), // CHOSEONG IEUNG-SIOS
+    (Choseong, '\u{110B}', 'ㅈ', '\u{1148}'), // CHOSEONG IEUNG-CIEUC
+    (Choseong, '\u{110B}', 'ㅊ', '\u{1149}'), // CHOSEONG IEUNG-CHIEUCH
+    (Choseong, '\u{110B}', 'ㅌ', '\u{114A}'), // CHOSEONG IEUNG-THIEUTH
+    (Choseong, '\u{110B}', 'ㅍ', '\u{114B}'), // CHOSEONG IEUNG-PHIEUPH
+    (Choseong, '\u{110B}', 'ㅎ', '\u{A977}'), // CHOSEONG IEUNG-HIEUH
+    (Choseong, '\u{110B}', 'ㅿ', '\u{1146}'), // CHOSEONG IEUNG-PANSIOS
+    (Choseong, '\u{110C}', 'ㅇ', '\u{114D}'), // CHOSEONG CIEUC-IEUNG
+    (Choseong, '\u{110D}', 'ㅎ', '\u{A978}'), // CHOSEONG SSANGCIEUC-HIEUH
+    (Choseong, '\u{110E}', 'ㅋ', '\u{1152}'), // CHOSEONG CHIEUCH-KHIEUKH
+    (Choseong, '\u{110E}', 'ㅎ', '\u{1153}'), // CHOSEONG CHIEUCH-HIEUH
+    (Choseong, '\u{1110}', 'ㅌ', '\u{A979}'), // CHOSEONG SSANGTHIEUTH
+    (Choseong, '\u{1111}', 'ㅂ', '\u{1156}'), // CHOSEONG PHIEUPH-PIEUP
+    (Choseong, '\u{1111}', 'ㅇ', '\u{1157}'), // CHOSEONG KAPYEOUNPHIEUPH
+    (Choseong, '\u{1111}', 'ㅎ', '\u{A97A}'), // CHOSEONG PHIEUPH-HIEUH
+    (Choseong, '\u{1112}', 'ㅅ', '\u{A97B}'), // CHOSEONG HIEUH-SIOS
+    (Choseong, '\u{1121}', 'ㄱ', '\u{1122}'), // CHOSEONG PIEUP-SIOS-KIYEOK
+    (Choseong, '\u{1121}', 'ㄷ', '\u{1123}'), // CHOSEONG PIEUP-SIOS-TIKEUT
+    (Choseong, '\u{1121}', 'ㅂ', '\u{1124}'), // CHOSEONG PIEUP-SIOS-PIEUP
+    (Choseong, '\u{1121}', 'ㅈ', '\u{1126}'), // CHOSEONG PIEUP-SIOS-CIEUC
+    (Choseong, '\u{1121}', 'ㅌ', '\u{A972}'), // CHOSEONG PIEUP-SIOS-THIEUTH
+    (Choseong, '\u{1132}', 'ㄱ', '\u{1133}'), // CHOSEONG SIOS-PIEUP-KIYEOK
+    (Choseong, '\u{1159}', 'ㆆ', '\u{A97C}'), // CHOSEONG SSANGYEORINHIEUH
+    (Choseong, '\u{A969}', 'ㅇ', '\u{A96B}'), // CHOSEONG RIEUL-KAPYEOUNPIEUP
+    (Jungseong, '\u{1161}', 'ㅗ', '\u{1176}'), // JUNGSEONG A-O
+    (Jungseong, '\u{1161}', 'ㅜ', '\u{1177}'), // JUNGSEONG A-U
+    (Jungseong, '\u{1161}', 'ㅡ', '\u{11A3}'), // JUNGSEONG A-EU
+    (Jungseong, '\u{1163}', 'ㅗ', '\u{1178}'), // JUNGSEONG YA-O
+    (Jungseong, '\u{1163}', 'ㅛ', '\u{1179}'), // JUNGSEONG YA-YO
+    (Jungseong, '\u{1163}', 'ㅜ', '\u{11A4}'), // JUNGSEONG YA-U
+    (Jungseong, '\u{1165}', 'ㅗ', '\u{117A}'), // JUNGSEONG EO-O
+    (Jungseong, '\u{1165}', 'ㅜ', '\u{117B}'), // JUNGSEONG EO-U
+    (Jungseong, '\u{1165}', 'ㅡ', '\u{117C}'), // JUNGSEONG EO-EU
+    (Jungseong, '\u{1167}', 'ㅑ', '\u{11A5}'), // JUNGSEONG YEO-YA
+    (Jungseong, '\u{1167}', 'ㅗ', '\u{117D}'), // JUNGSEONG YEO-O
+    (Jungseong, '\u{1167}', 'ㅜ', '\u{117E}'), // JUNGSEONG YEO-U
+    (Jungseong, '\u{1169}', 'ㅏ', '\u{116A}'), // JUNGSEONG WA
+    (Jungseong, '\u{1169}', 'ㅐ', '\u{116B}'), // JUNGSEONG WAE
+    (Jungseong, '\u{1169}', 'ㅑ', '\u{11A6}'), // JUNGSEONG O-YA
+    (Jungseong, '\u{1169}', 'ㅒ', '\u{11A7}'), // JUNGSEONG O-YAE
+    (Jungseong, '\u{1169}', 'ㅓ', '\u{117F}'), // JUNGSEONG O-EO
+    (Jungseong, '\u{1169}', 'ㅔ', '\u{1180}'), // JUNGSEONG O-E
+    (Jungseong, '\u{1169}', 'ㅕ', '\u{D7B0}'), // JUNGSEONG O-YEO
+    (Jungseong, '\u{1169}', 'ㅖ', '\u{1181}'), // JUNGSEONG O-YE
+    (Jungseong, '\u{1169}', 'ㅗ', '\u{1182}'), // JUNGSEONG O-O
+    (Jungseong, '\u{1169}', 'ㅜ', '\u{1183}'), // JUNGSEONG O-U
+    (Jungseong, '\u{1169}', 'ㅣ', '\u{116C}'), // JUNGSEONG OE
+    (Jungseong, '\u{116D}', 'ㅏ', '\u{D7B2}'), // JUNGSEONG YO-A
+    (Jungseong, '\u{116D}', 'ㅐ', '\u{D7B3}'), // JUNGSEONG YO-AE
+    (Jungseong, '\u{116D}', 'ㅑ', '\u{1184}'), // JUNGSEONG YO-YA
+    (Jungseong, '\u{116D}', 'ㅒ', '\u{1185}'), // JUNGSEONG YO-YAE
+    (Jungseong, '\u{116D}', 'ㅓ', '\u{D7B4}'), // JUNGSEONG YO-EO
+    (Jungseong, '\u{116D}', 'ㅕ', '\u{1186}'), // JUNGSEONG YO-YEO
+    (Jungseong, '\u{116D}', 'ㅗ', '\u{1187}'), // JUNGSEONG YO-O
+    (Jungseong, '\u{116D}', 'ㅣ', '\u{1188}'), // JUNGSEONG YO-I
+    (Jungseong, '\u{116E}', 'ㅏ', '\u{1189}'), // JUNGSEONG U-A
+    (Jungseong, '\u{116E}', 'ㅐ', '\u{118A}'), // JUNGSEONG U-AE
+    (Jungseong, '\u{116E}', 'ㅓ', '\u{116F}'), // JUNGSEONG WEO
+    (Jungseong, '\u{116E}', 'ㅔ', '\u{1170}'), // JUNGSEONG WE
+    (Jungseong, '\u{116E}', 'ㅕ', '\u{D7B5}'), // JUNGSEONG U-YEO
+    (Jungseong, '\u{116E}', 'ㅖ', '\u{118C}'), // JUNGSEONG U-YE
+    (Jungseong, '\u{116E}', 'ㅜ', '\u{118D}'), // JUNGSEONG U-U
+    (Jungseong, '\u{116E}', 'ㅣ', '\u{1171}'), // JUNGSEONG WI
+    (Jungseong, '\u{116F}', 'ㅡ', '\u{118B}'), // JUNGSEONG U-EO-EU
+    (Jungseong, '\u{1171}', 'ㅣ', '\u{D7B6}'), // JUNGSEONG U-I-I
+    (Jungseong, '\u{1172}', 'ㅏ', '\u{118E}'), // JUNGSEONG YU-A
+    (Jungseong, '\u{1172}', 'ㅐ', '\u{D7B7}'), // JUNGSEONG YU-AE
+    (Jungseong, '\u{1172}', 'ㅓ', '\u{118F}'), // JUNGSEONG YU-EO
+    (Jungseong, '\u{1172}', 'ㅔ', '\u{1190}'), // JUNGSEONG YU-E
+    (Jungseong, '\u{1172}', 'ㅕ', '\u{1191}'), // JUNGSEONG YU-YEO
+    (Jungseong, '\u{1172}', 'ㅖ', '\u{1192}'), // JUNGSEONG YU-YE
+    (Jungseong, '\u{1172}', 'ㅗ', '\u{D7B8}'), // JUNGSEONG YU-O
+    (Jungseong, '\u{1172}', 'ㅜ', '\u{1193}'), // JUNGSEONG YU-U
+    (Jungseong, '\u{1172}', 'ㅣ', '\u{1194}'), // JUNGSEONG YU-I
+    (Jungseong, '\u{1173}', 'ㅏ', '\u{D7B9}'), // JUNGSEONG EU-A
+    (Jungseong, '\u{1173}', 'ㅓ', '\u{D7BA}'), // JUNGSEONG EU-EO
+    (Jungseong, '\u{1173}', 'ㅔ', '\u{D7BB}'), // JUNGSEONG EU-E
+    (Jungseong, '\u{1173}', 'ㅗ', '\u{D7BC}'), // JUNGSEONG EU-O
+    (Jungseong, '\u{1173}', 'ㅜ', '\u{1195}'), // JUNGSEONG EU-U
+    (Jungseong, '\u{1173}', 'ㅡ', '\u{1196}'), // JUNGSEONG EU-EU
+    (Jungseong, '\u{1173}', 'ㅣ', '\u{1174}'), // JUNGSEONG YI
+    (Jungseong, '\u{1174}', 'ㅜ', '\u{1197}'), // JUNGSEONG YI-U
+    (Jungseong, '\u{1175}', 'ㅏ', '\u{1198}'), // JUNGSEONG I-A
+    (Jungseong, '\u{1175}', 'ㅑ', '\u{1199}'), // JUNGSEONG I-YA
+    (Jungseong, '\u{1175}', 'ㅒ', '\u{D7BE}'), // JUNGSEONG I-YAE
+    (Jungseong, '\u{1175}', 'ㅕ', '\u{D7BF}'), // JUNGSEONG I-YEO
+    (Jungseong, '\u{1175}', 'ㅖ', '\u{D7C0}'), // JUNGSEONG I-YE
+    (Jungseong, '\u{1175}', 'ㅗ', '\u{119A}'), // JUNGSEONG I-O
+    (Jungseong, '\u{1175}', 'ㅛ', '\u{D7C2}'), // JUNGSEONG I-YO
+    (Jungseong, '\u{1175}', 'ㅜ', '\u{119B}'), // JUNGSEONG I-U
+    (Jungseong, '\u{1175}', 'ㅠ', '\u{D7C3}'), // JUNGSEONG I-YU
+    (Jungseong, '\u{1175}', 'ㅡ', '\u{119C}'), // JUNGSEONG I-EU
+    (Jungseong, '\u{1175}', 'ㅣ', '\u{D7C4}'), // JUNGSEONG I-I
+    (Jungseong, '\u{1175}', 'ㆍ', '\u{119D}'), // JUNGSEONG I-ARAEA
+    (Jungseong, '\u{1182}', 'ㅣ', '\u{D7B1}'), // JUNGSEONG O-O-I
+    (Jungseong, '\u{1199}', 'ㅗ', '\u{D7BD}'), // JUNGSEONG I-YA-O
+    (Jungseong, '\u{119A}', 'ㅣ', '\u{D7C1}'), // JUNGSEONG I-O-I
+    (Jungseong, '\u{119E}', 'ㅏ', '\u{D7C5}'), // JUNGSEONG ARAEA-A
+    (Jungseong, '\u{119E}', 'ㅓ', '\u{119F}'), // JUNGSEONG ARAEA-EO
+    (Jungseong, '\u{119E}', 'ㅔ', '\u{D7C6}'), // JUNGSEONG ARAEA-E
+    (Jungseong, '\u{119E}', 'ㅜ', '\u{11A0}'), // JUNGSEONG ARAEA-U
+    (Jungseong, '\u{119E}', 'ㅣ', '\u{11A1}'), // JUNGSEONG ARAEA-I
+    (Jungseong, '\u{119E}', 'ㆍ', '\u{11A2}'), // JUNGSEONG SSANGARAEA
+    (Jongseong, '\u{11A8}', 'ㄴ', '\u{11FA}'), // JONGSEONG KIYEOK-NIEUN
+    (Jongseong, '\u{11A8}', 'ㄹ', '\u{11C3}'), // JONGSEONG KIYEOK-RIEUL
+    (Jongseong, '\u{11A8}', 'ㅂ', '\u{11FB}'), // JONGSEONG KIYEOK-PIEUP
+    (Jongseong, '\u{11A8}', 'ㅅ', '\u{11AA}'), // JONGSEONG KIYEOK-SIOS
+    (Jongseong, '\u{11A8}', 'ㅊ', '\u{11FC}'), // JONGSEONG KIYEOK-CHIEUCH
+    (Jongseong, '\u{11A8}', 'ㅋ', '\u{11FD}'), // JONGSEONG KIYEOK-KHIEUKH
+    (Jongseong, '\u{11A8}', 'ㅎ', '\u{11FE}'), // JONGSEONG KIYEOK-HIEUH
+    (Jongseong, '\u{11AA}', 'ㄱ', '\u{11C4}'), // JONGSEONG KIYEOK-SIOS-KIYEOK
+    (Jongseong, '\u{11AB}', 'ㄱ', '\u{11C5}'), // JONGSEONG NIEUN-KIYEOK
+    (Jongseong, '\u{11AB}', 'ㄷ', '\u{11C6}'), // JONGSEONG NIEUN-TIKEUT
+    (Jongseong, '\u{11AB}', 'ㄹ', '\u{D7CB}'), // JONGSEONG NIEUN-RIEUL
+    (Jongseong, '\u{11AB}', 'ㅅ', '\u{11C7}'), // JONGSEONG NIEUN-SIOS
+    (Jongseong, '\u{11AB}', 'ㅈ', '\u{11AC}'), // JONGSEONG NIEUN-CIEUC
+    (Jongseong, '\u{11AB}', 'ㅊ', '\u{D7CC}'), // JONGSEONG NIEUN-CHIEUCH
+    (Jongseong, '\u{11AB}', 'ㅌ', '\u{11C9}'), // JONGSEONG NIEUN-THIEUTH
+    (Jongseong, '\u{11AB}', 'ㅎ', '\u{11AD}'), // JONGSEONG NIEUN-HIEUH
+    (Jongseong, '\u{11AB}', 'ㅿ', '\u{11C8}'), // JONGSEONG NIEUN-PANSIOS
+    (Jongseong, '\u{11AE}', 'ㄱ', '\u{11CA}'), // JONGSEONG TIKEUT-KIYEOK
+    (Jongseong, '\u{11AE}', 'ㄹ', '\u{11CB}'), // JONGSEONG TIKEUT-RIEUL
+    (Jongseong, '\u{11AE}', 'ㅂ', '\u{D7CF}'), // JONGSEONG TIKEUT-PIEUP
+    (Jongseong, '\u{11AE}', 'ㅅ', '\u{D7D0}'), // JONGSEONG TIKEUT-SIOS
+    (Jongseong, '\u{11AE}', 'ㅈ', '\u{D7D2}'), // JONGSEONG TIKEUT-CIEUC
+    (Jongseong, '\u{11AE}', 'ㅊ', '\u{D7D3}'), // JONGSEONG TIKEUT-CHIEUCH
+    (Jongseong, '\u{11AE}', 'ㅌ', '\u{D7D4}'), // JONGSEONG TIKEUT-THIEUTH
+    (Jongseong, '\u{11AF}', 'ㄱ', '\u{11B0}'), // JONGSEONG RIEUL-KIYEOK
+    (Jongseong, '\u{11AF}', 'ㄲ', '\u{D7D5}'), // JONGSEONG RIEUL-SSANGKIYEOK
+    (Jongseong, '\u{11AF}', 'ㄴ', '\u{11CD}'), // JONGSEONG RIEUL-NIEUN
+    (Jongseong, '\u{11AF}', 'ㄷ', '\u{11CE}'), // JONGSEONG RIEUL-TIKEUT
+    (Jongseong, '\u{11AF}', 'ㄹ', '\u{11D0}'), // JONGSEONG SSANGRIEUL
+    (Jongseong, '\u{11AF}', 'ㅁ', '\u{11B1}'), // JONGSEONG RIEUL-MIEUM
+    (Jongseong, '\u{11AF}', 'ㅂ', '\u{11B2}'), // JONGSEONG RIEUL-PIEUP
+    (Jongseong, '\u{11AF}', 'ㅅ', '\u{11B3}'), // JONGSEONG RIEUL-SIOS
+    (Jongseong, '\u{11AF}', 'ㅆ', '\u{11D6}'), // JONGSEONG RIEUL-SSANGSIOS
+    (Jongseong, '\u{11AF}', 'ㅇ', '\u{D7DD}'), // JONGSEONG KAPYEOUNRIEUL
+    (Jongseong, '\u{11AF}', 'ㅋ', '\u{11D8}'), // JONGSEONG RIEUL-KHIEUKH
+    (Jongseong, '\u{11AF}', 'ㅌ', '\u{11B4}'), // JONGSEONG RIEUL-THIEUTH
+    (Jongseong, '\u{11AF}', 'ㅍ', '\u{11B5}'), // JONGSEONG RIEUL-PHIEUPH
+    (Jongseong, '\u{11AF}', 'ㅎ', '\u{11B6}'), // JONGSEONG RIEUL-HIEUH
+    (Jongseong, '\u{11AF}', 'ㅸ', '\u{11D5}'), // JONGSEONG RIEUL-KAPYEOUNPIEUP
+    (Jongseong, '\u{11AF}', 'ㅿ', '\u{11D7}'), // JONGSEONG RIEUL-PANSIOS
+    (Jongseong, '\u{11AF}', 'ㆁ', '\u{D7DB}'), // JONGSEONG RIEUL-YESIEUNG
+    (Jongseong, '\u{11AF}', 'ㆆ', '\u{11D9}'), // JONGSEONG RIEUL-YEORINHIEUH
+    (Jongseong, '\u{11B0}', 'ㅅ', '\u{11CC}'), // JONGSEONG RIEUL-KIYEOK-SIOS
+    (Jongseong, '\u{11B0}', 'ㅎ', '\u{D7D6}'), // JONGSEONG RIEUL-KIYEOK-HIEUH
+    (Jongseong, '\u{11B1}', 'ㄱ', '\u{11D1}'), // JONGSEONG RIEUL-MIEUM-KIYEOK
+    (Jongseong, '\u{11B1}', 'ㅅ', '\u{11D2}'), // JONGSEONG RIEUL-MIEUM-SIOS
+    (Jongseong, '\u{11B1}', 'ㅎ', '\u{D7D8}'), // JONGSEONG RIEUL-MIEUM-HIEUH
+    (Jongseong, '\u{11B2}', 'ㄷ', '\u{D7D9}'), // JONGSEONG RIEUL-PIEUP-TIKEUT
+    (Jongseong, '\u{11B2}', 'ㅅ', '\u{11D3}'), // JONGSEONG RIEUL-PIEUP-SIOS
+    (Jongseong, '\u{11B2}', 'ㅇ', '\u{11D5}'), // JONGSEONG RIEUL-KAPYEOUNPIEUP
+    (Jongseong, '\u{11B2}', 'ㅍ', '\u{D7DA}'), // JONGSEONG RIEUL-PIEUP-PHIEUPH
+    (Jongseong, '\u{11B2}', 'ㅎ', '\u{11D4}'), // JONGSEONG RIEUL-PIEUP-HIEUH
+    (Jongseong, '\u{11B7}', 'ㄱ', '\u{11DA}'), // JONGSEONG MIEUM-KIYEOK
+    (Jongseong, '\u{11B7}', 'ㄴ', '\u{D7DE}'), // JONGSEONG MIEUM-NIEUN
+    (Jongseong, '\u{11B7}', 'ㄹ', '\u{11DB}'), // JONGSEONG MIEUM-RIEUL
+    (Jongseong, '\u{11B7}', 'ㅁ', '\u{D7E0}'), // JONGSEONG SSANGMIEUM
+    (Jongseong, '\u{11B7}', 'ㅂ', '\u{11DC}'), // JONGSEONG MIEUM-PIEUP
+    (Jongseong, '\u{11B7}', 'ㅅ', '\u{11DD}'), // JONGSEONG MIEUM-SIOS
+    (Jongseong, '\u{11B7}', 'ㅆ', '\u{11DE}'), // JONGSEONG MIEUM-SSANGSIOS
+    (Jongseong, '\u{11B7}', 'ㅇ', '\u{11E2}'), // JONGSEONG KAPYEOUNMIEUM
+    (Jongseong, '\u{11B7}', 'ㅈ', '\u{D7E2}'), // JONGSEONG MIEUM-CIEUC
+    (Jongseong, '\u{11B7}', 'ㅊ', '\u{11E0}'), // JONGSEONG MIEUM-CHIEUCH
+    (Jongseong, '\u{11B7}', 'ㅎ', '\u{11E1}'), // JONGSEONG MIEUM-HIEUH
+    (Jongseong, '\u{11B7}', 'ㅥ', '\u{D7DF}'), // JONGSEONG MIEUM-SSANGNIEUN
+    (Jongseong, '\u{11B7}', 'ㅿ', '\u{11DF}'), // JONGSEONG MIEUM-PANSIOS
+    (Jongseong, '\u{11B8}', 'ㄷ', '\u{D7E3}'), // JONGSEONG PIEUP-TIKEUT
+    (Jongseong, '\u{11B8}', 'ㄹ', '\u{11E3}'), // JONGSEONG PIEUP-RIEUL
+    (Jongseong, '\u{11B8}', 'ㅁ', '\u{D7E5}'), // JONGSEONG PIEUP-MIEUM
+    (Jongseong, '\u{11B8}', 'ㅅ', '\u{11B9}'), // JONGSEONG PIEUP-SIOS
+    (Jongseong, '\u{11B8}', 'ㅇ', '\u{11E6}'), // JONGSEONG KAPYEOUNPIEUP
+    (Jongseong, '\u{11B8}', 'ㅈ', '\u{D7E8}'), // JONGSEONG PIEUP-CIEUC
+    (Jongseong, '\u{11B8}', 'ㅊ', '\u{D7E9}'), // JONGSEONG PIEUP-CHIEUCH
+    (Jongseong, '\u{11B8}', 'ㅍ', '\u{11E4}'), // JONGSEONG PIEUP-PHIEUPH
+    (Jongseong, '\u{11B8}', 'ㅎ', '\u{11E5}'), // JONGSEONG PIEUP-HIEUH
+    (Jongseong, '\u{11B9}', 'ㄷ', '\u{D7E7}'), // JONGSEONG PIEUP-SIOS-TIKEUT
+    (Jongseong, '\u{11BA}', 'ㄱ', '\u{11E7}'), // JONGSEONG SIOS-KIYEOK
+    (Jongseong, '\u{11BA}', 'ㄷ', '\u{11E8}'), // JONGSEONG SIOS-TIKEUT
+    (Jongseong, '\u{11BA}', 'ㄹ', '\u{11E9}'), // JONGSEONG SIOS-RIEUL
+    (Jongseong, '\u{11BA}', 'ㅁ', '\u{D7EA}'), // JONGSEONG SIOS-MIEUM
+    (Jongseong, '\u{11BA}', 'ㅂ', '\u{11EA}'), // JONGSEONG SIOS-PIEUP
+    (Jongseong, '\u{11BA}', 'ㅈ', '\u{D7EF}'), // JONGSEONG SIOS-CIEUC
+    (Jongseong, '\u{11BA}', 'ㅊ', '\u{D7F0}'), // JONGSEONG SIOS-CHIEUCH
+    (Jongseong, '\u{11BA}', 'ㅌ', '\u{D7F1}'), // JONGSEONG SIOS-THIEUTH
+    (Jongseong, '\u{11BA}', 'ㅎ', '\u{D7F2}'), // JONGSEONG SIOS-HIEUH
+    (Jongseong, '\u{11BA}', 'ㅸ', '\u{D7EB}'), // JONGSEONG SIOS-KAPYEOUNPIEUP
+    (Jongseong, '\u{11BA}', 'ㅿ', '\u{D7EE}'), // JONGSEONG SIOS-PANSIOS
+    (Jongseong, '\u{11BB}', 'ㄱ', '\u{D7EC}'), // JONGSEONG SSANGSIOS-KIYEOK
+    (Jongseong, '\u{11BB}', 'ㄷ', '\u{D7ED}'), // JONGSEONG SSANGSIOS-TIKEUT
+    (Jongseong, '\u{11BC}', 'ㄱ', '\u{11EC}'), // JONGSEONG IEUNG-KIYEOK
+    (Jongseong, '\u{11BC}', 'ㄲ', '\u{11ED}'), // JONGSEONG IEUNG-SSANGKIYEOK
+    (Jongseong, '\u{11BC}', 'ㅋ', '\u{11EF}'), // JONGSEONG IEUNG-KHIEUKH
+    (Jongseong, '\u{11BD}', 'ㅂ', '\u{D7F7}'), // JONGSEONG CIEUC-PIEUP
+    (Jongseong, '\u{11BD}', 'ㅃ', '\u{D7F8}'), // JONGSEONG CIEUC-SSANGPIEUP
+    (Jongseong, '\u{11C1}', 'ㅂ', '\u{11F3}'), // JONGSEONG PHIEUPH-PIEUP
+    (Jongseong, '\u{11C1}', 'ㅅ', '\u{D7FA}'), // JONGSEONG PHIEUPH-SIOS
+    (Jongseong, '\u{11C1}', 'ㅇ', '\u{11F4}'), // JONGSEONG KAPYEOUNPHIEUPH
+    (Jongseong, '\u{11C1}', 'ㅌ', '\u{D7FB}'), // JONGSEONG PHIEUPH-THIEUTH
+    (Jongseong, '\u{11C2}', 'ㄴ', '\u{11F5}'), // JONGSEONG HIEUH-NIEUN
+    (Jongseong, '\u{11C2}', 'ㄹ', '\u{11F6}'), // JONGSEONG HIEUH-RIEUL
+    (Jongseong, '\u{11C2}', 'ㅁ', '\u{11F7}'), // JONGSEONG HIEUH-MIEUM
+    (Jongseong, '\u{11C2}', 'ㅂ', '\u{11F8}'), // JONGSEONG HIEUH-PIEUP
+    (Jongseong, '\u{11CE}', 'ㅎ', '\u{11CF}'), // JONGSEONG RIEUL-TIKEUT-HIEUH
+    (Jongseong, '\u{11D0}', 'ㅋ', '\u{D7D7}'), // JONGSEONG SSANGRIEUL-KHIEUKH
+    (Jongseong, '\u{11D9}', 'ㅎ', '\u{D7DC}'), // JONGSEONG RIEUL-YEORINHIEUH-HIEUH
+    (Jongseong, '\u{11DC}', 'ㅅ', '\u{D7E1}'), // JONGSEONG MIEUM-PIEUP-SIOS
+    (Jongseong, '\u{11E3}', 'ㅍ', '\u{D7E4}'), // JONGSEONG PIEUP-RIEUL-PHIEUPH
+    (Jongseong, '\u{11EA}', 'ㅇ', '\u{D7EB}'), // JONGSEONG SIOS-KAPYEOUNPIEUP
+    (Jongseong, '\u{11EB}', 'ㅂ', '\u{D7F3}'), // JONGSEONG PANSIOS-PIEUP
+    (Jongseong, '\u{11EB}', 'ㅸ', '\u{D7F4}'), // JONGSEONG PANSIOS-KAPYEOUNPIEUP
+    (Jongseong, '\u{11F0}', 'ㅁ', '\u{D7F5}'), // JONGSEONG YESIEUNG-MIEUM
+    (Jongseong, '\u{11F0}', 'ㅅ', '\u{11F1}'), // JONGSEONG YESIEUNG-SIOS
+    (Jongseong, '\u{11F0}', 'ㅎ', '\u{D7F6}'), // JONGSEONG YESIEUNG-HIEUH
+    (Jongseong, '\u{11F0}', 'ㅿ', '\u{11F2}'), // JONGSEONG YESIEUNG-PANSIOS
+    (Jongseong, '\u{D7CD}', 'ㅂ', '\u{D7CE}'), // JONGSEONG SSANGTIKEUT-PIEUP
+    (Jongseong, '\u{D7D0}', 'ㄱ', '\u{D7D1}'), // JONGSEONG TIKEUT-SIOS-KIYEOK
+    (Jongseong, '\u{D7F3}', 'ㅇ', '\u{D7F4}'), // JONGSEONG PANSIOS-KAPYEOUNPIEUP
+];
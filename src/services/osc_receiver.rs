@@ -0,0 +1,195 @@
+// src/services/osc_receiver.rs
+//
+// OSC remote control, so a show controller (QLab, Max …) can cue the piece.
+// A thread blocks on the UDP port and turns messages into commands; the app
+// drains them once per frame in `update`.
+//
+// Address space:
+//   /text/append s      type text into the composer
+//   /text/clear         clear the line being composed
+//   /text/submit        submit it, as Enter does
+//   /bg/flash fff f     flash the background to r g b (0.0 - 1.0) for f seconds
+//   /bg/fade fff f      fade the background to r g b over f seconds
+//   /verbose i          terminal messages on (non-zero) or off
+// Numbers may be sent as ints or floats.
+
+use nannou_osc as osc;
+use std::sync::mpsc::{self, Receiver};
+use std::thread;
+use std::time::Duration;
+
+// After a socket error the listener waits before trying again, a little
+// longer for each error in a row, up to the cap.
+const SOCKET_ERROR_BACKOFF: Duration = Duration::from_millis(200);
+const MAX_SOCKET_BACKOFF: Duration = Duration::from_secs(5);
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum OscCommand {
+    TextAppend(String),
+    TextClear,
+    TextSubmit,
+    BgFlash { color: [f32; 3], duration: f32 },
+    BgFade { color: [f32; 3], duration: f32 },
+    Verbose(bool),
+}
+
+impl OscCommand {
+    /// Read one message. The error says what was wrong with it.
+    pub fn parse(addr: &str, args: &[osc::Type]) -> Result<Self, String> {
+        let command = match addr {
+            "/text/append" => match args {
+                [osc::Type::String(text)] => OscCommand::TextAppend(text.clone()),
+                _ => return Err(format!("{} expects one string", addr)),
+            },
+            "/text/clear" => OscCommand::TextClear,
+            "/text/submit" => OscCommand::TextSubmit,
+            "/bg/flash" | "/bg/fade" => {
+                let numbers: Option<Vec<f32>> = args.iter().map(number).collect();
+                let Some(&[r, g, b, duration]) = numbers.as_deref() else {
+                    return Err(format!("{} expects r g b duration", addr));
+                };
+                let color = [r, g, b];
+                if addr == "/bg/flash" {
+                    OscCommand::BgFlash { color, duration }
+                } else {
+                    OscCommand::BgFade { color, duration }
+                }
+            }
+            "/verbose" => match args.first().and_then(number) {
+                Some(on) => OscCommand::Verbose(on != 0.0),
+                None => return Err(format!("{} expects a number", addr)),
+            },
+            _ => return Err(format!("unknown address {}", addr)),
+        };
+        Ok(command)
+    }
+}
+
+fn number(arg: &osc::Type) -> Option<f32> {
+    match arg {
+        osc::Type::Float(f) => Some(*f),
+        osc::Type::Double(d) => Some(*d as f32),
+        osc::Type::Int(i) => Some(*i as f32),
+        osc::Type::Long(l) => Some(*l as f32),
+        _ => None,
+    }
+}
+
+pub struct OscReceiver {
+    commands: Receiver<OscCommand>,
+}
+
+impl OscReceiver {
+    /// Bind `port` and start listening on a background thread.
+    pub fn spawn(port: u16) -> Result<Self, Box<dyn std::error::Error>> {
+        let receiver = osc::receiver(port)?;
+        let (tx, commands) = mpsc::channel();
+
+        thread::Builder::new()
+            .name("osc_receiver".to_owned())
+            .spawn(move || {
+                let mut socket_errors: u32 = 0;
+                loop {
+                    let (packet, from) = match receiver.recv() {
+                        Ok(received) => received,
+                        // a bad packet: drop it and keep listening
+                        Err(osc::CommunicationError::Osc(e)) => {
+                            println!("OSC: could not decode packet: {:?}", e);
+                            continue;
+                        }
+                        Err(osc::CommunicationError::Io(e)) => {
+                            socket_errors = socket_errors.saturating_add(1);
+                            let backoff =
+                                (SOCKET_ERROR_BACKOFF * socket_errors).min(MAX_SOCKET_BACKOFF);
+                            println!(
+                                "OSC receive error, retrying in {:.1}s: {}",
+                                backoff.as_secs_f32(),
+                                e
+                            );
+                            thread::sleep(backoff);
+                            continue;
+                        }
+                    };
+                    if socket_errors > 0 {
+                        println!("OSC: receiving again");
+                        socket_errors = 0;
+                    }
+                    for message in packet.into_msgs() {
+                        match OscCommand::parse(&message.addr, &message.args) {
+                            Ok(command) => {
+                                // the app has quit
+                                if tx.send(command).is_err() {
+                                    return;
+                                }
+                            }
+                            Err(e) => println!("OSC from {}: {}", from, e),
+                        }
+                    }
+                }
+            })?;
+
+        Ok(Self { commands })
+    }
+
+    /// Commands received since the last call, oldest first.
+    pub fn drain(&self) -> Vec<OscCommand> {
+        self.commands.try_iter().collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use osc::Type::*;
+
+    #[test]
+    fn test_text_commands() {
+        assert_eq!(
+            OscCommand::parse("/text/append", &[String("아리랑".into())]),
+            Ok(OscCommand::TextAppend("아리랑".into()))
+        );
+        assert_eq!(
+            OscCommand::parse("/text/submit", &[]),
+            Ok(OscCommand::TextSubmit)
+        );
+        assert_eq!(
+            OscCommand::parse("/text/clear", &[]),
+            Ok(OscCommand::TextClear)
+        );
+        assert!(OscCommand::parse("/text/append", &[Int(1)]).is_err());
+    }
+
+    #[test]
+    fn test_background_commands() {
+        assert_eq!(
+            OscCommand::parse("/bg/flash", &[Float(1.0), Int(0), Float(0.5), Float(0.2)]),
+            Ok(OscCommand::BgFlash {
+                color: [1.0, 0.0, 0.5],
+                duration: 0.2
+            })
+        );
+        assert_eq!(
+            OscCommand::parse("/bg/fade", &[Int(0), Int(0), Int(1), Int(3)]),
+            Ok(OscCommand::BgFade {
+                color: [0.0, 0.0, 1.0],
+                duration: 3.0
+            })
+        );
+        assert!(OscCommand::parse("/bg/fade", &[Float(1.0)]).is_err());
+        assert!(OscCommand::parse("/bg/flash", &[String("red".into())]).is_err());
+    }
+
+    #[test]
+    fn test_verbose_and_unknown() {
+        assert_eq!(
+            OscCommand::parse("/verbose", &[Int(1)]),
+            Ok(OscCommand::Verbose(true))
+        );
+        assert_eq!(
+            OscCommand::parse("/verbose", &[Float(0.0)]),
+            Ok(OscCommand::Verbose(false))
+        );
+        assert!(OscCommand::parse("/verbose", &[]).is_err());
+        assert!(OscCommand::parse("/nope", &[]).is_err());
+    }
+}
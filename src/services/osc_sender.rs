@@ -0,0 +1,154 @@
+// src/services/osc_sender.rs
+//
+// Composer events out over OSC, for a sound engine (SuperCollider …) that
+// follows every keystroke. Every message goes to every target:
+//   /jamo s i            jamo typed, role (0 choseong, 1 jungseong, 2 jongseong)
+//   /syllable s i i i i  committed syllable, codepoint, choseong, jungseong
+//                        and jongseong index (jongseong 0 = no final)
+//   /line s              submitted line or stanza, in the export normal form
+//   /backspace
+// Indices follow Unicode syllable order (choseong 0-18, jungseong 0-20,
+// jongseong 0-27), so ㄱ ㅏ is 0 0 and 각 ends in 1.
+
+use super::composer_event::ComposerEvent;
+use super::jamo::{is_syllable, JamoRole};
+use super::normalization::{to_normal_form, NormalForm};
+use nannou_osc as osc;
+
+const SYLLABLE_BASE: u32 = 0xAC00;
+
+pub struct OscSender {
+    targets: Vec<(String, osc::Sender<osc::send::Connected>)>,
+    export_form: NormalForm,
+}
+
+impl OscSender {
+    /// Connect to each `host:port`. Targets that can't be reached are
+    /// reported and left out.
+    pub fn new(addresses: &[String], export_form: NormalForm) -> Self {
+        let targets = addresses
+            .iter()
+            .filter_map(|address| {
+                let connected = osc::sender().and_then(|sender| sender.connect(address.as_str()));
+                match connected {
+                    Ok(sender) => Some((address.clone(), sender)),
+                    Err(e) => {
+                        println!("OSC: could not send to {}: {}", address, e);
+                        None
+                    }
+                }
+            })
+            .collect();
+        Self {
+            targets,
+            export_form,
+        }
+    }
+
+    pub fn send_event(&self, event: &ComposerEvent) {
+        for message in messages(event, self.export_form) {
+            for (address, sender) in &self.targets {
+                if let Err(e) = sender.send(message.clone()) {
+                    println!("OSC: sending {} to {} failed: {}", message.addr, address, e);
+                }
+            }
+        }
+    }
+}
+
+/// The messages an event goes out as; none for events the sound engine
+/// doesn't follow.
+pub fn messages(event: &ComposerEvent, export_form: NormalForm) -> Vec<osc::Message> {
+    match event {
+        ComposerEvent::JamoTyped { jamo, role } => {
+            let role = match role {
+                JamoRole::Choseong => 0,
+                JamoRole::Jungseong => 1,
+                JamoRole::Jongseong => 2,
+            };
+            vec![message(
+                "/jamo",
+                vec![osc::Type::String(jamo.to_string()), osc::Type::Int(role)],
+            )]
+        }
+        ComposerEvent::SyllableCommitted(text) => text
+            .chars()
+            .filter(|c| is_syllable(*c))
+            .map(syllable_message)
+            .collect(),
+        ComposerEvent::LineSubmitted(line) => vec![message(
+            "/line",
+            vec![osc::Type::String(to_normal_form(line, export_form))],
+        )],
+        ComposerEvent::Backspaced => vec![message("/backspace", Vec::new())],
+        _ => Vec::new(),
+    }
+}
+
+fn syllable_message(syllable: char) -> osc::Message {
+    let index = syllable as u32 - SYLLABLE_BASE;
+    message(
+        "/syllable",
+        vec![
+            osc::Type::String(syllable.to_string()),
+            osc::Type::Int(syllable as i32),
+            osc::Type::Int((index / (21 * 28)) as i32),
+            osc::Type::Int((index / 28 % 21) as i32),
+            osc::Type::Int((index % 28) as i32),
+        ],
+    )
+}
+
+fn message(addr: &str, args: Vec<osc::Type>) -> osc::Message {
+    osc::Message {
+        addr: addr.to_owned(),
+        args,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use osc::Type::*;
+
+    #[test]
+    fn test_jamo_and_backspace() {
+        let typed = ComposerEvent::JamoTyped {
+            jamo: 'ㄴ',
+            role: JamoRole::Jongseong,
+        };
+        let sent = messages(&typed, NormalForm::Nfc);
+        assert_eq!(sent[0].addr, "/jamo");
+        assert_eq!(sent[0].args, vec![String("ㄴ".into()), Int(2)]);
+
+        let sent = messages(&ComposerEvent::Backspaced, NormalForm::Nfc);
+        assert_eq!(sent[0].addr, "/backspace");
+        assert!(sent[0].args.is_empty());
+
+        let formed = ComposerEvent::SyllableFormed("가".into());
+        assert!(messages(&formed, NormalForm::Nfc).is_empty());
+    }
+
+    #[test]
+    fn test_syllable_indices() {
+        let committed = ComposerEvent::SyllableCommitted("각, 힣".into());
+        let sent = messages(&committed, NormalForm::Nfc);
+        assert_eq!(sent.len(), 2);
+        assert_eq!(
+            sent[0].args,
+            vec![String("각".into()), Int(0xAC01), Int(0), Int(0), Int(1)]
+        );
+        assert_eq!(sent[1].args[2..], [Int(18), Int(20), Int(27)]);
+    }
+
+    #[test]
+    fn test_line_in_export_form() {
+        let submitted = ComposerEvent::LineSubmitted("한".into());
+        let sent = messages(&submitted, NormalForm::Nfd);
+        assert_eq!(sent[0].addr, "/line");
+        assert_eq!(
+            sent[0].args,
+            vec![String("\u{1112}\u{1161}\u{11AB}".into())]
+        );
+    }
+}
@@ -0,0 +1,113 @@
+// src/services/predictor.rs
+//
+// Word completion from what has already been typed.
+// Every submitted line (and an optional corpus file) feeds a word count;
+// the word under the cursor is completed to the most frequent known word
+// it starts. Hangeul is matched by keystroke rather than by syllable, so
+// a half-typed 하 already offers 한글 (ㅎㅏ is a prefix of ㅎㅏㄴㄱㅡㄹ).
+
+use super::hangeul_composer::{is_punctuation, syllable_keystrokes};
+use std::collections::HashMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, Clone)]
+pub struct Predictor {
+    counts: HashMap<String, usize>,
+}
+
+impl Predictor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Learn every word of a corpus file.
+    pub fn load_corpus(&mut self, path: &Path) -> Result<(), Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        self.learn(&content);
+        Ok(())
+    }
+
+    /// Count the words of a text, punctuation stripped off their ends.
+    pub fn learn(&mut self, text: &str) {
+        for word in text.split_whitespace() {
+            let word = word.trim_matches(|c| is_punctuation(&c));
+            if word.chars().count() > 1 {
+                *self.counts.entry(word.to_owned()).or_default() += 1;
+            }
+        }
+    }
+
+    pub fn vocabulary_size(&self) -> usize {
+        self.counts.len()
+    }
+
+    /// The most frequent known word that `prefix` is the beginning of.
+    /// Ties go to the shorter word, then alphabetical order.
+    pub fn complete(&self, prefix: &str) -> Option<&str> {
+        if prefix.is_empty() {
+            return None;
+        }
+        let typed = keystrokes(prefix);
+
+        self.counts
+            .iter()
+            .filter(|(word, _)| word.as_str() != prefix)
+            .filter(|(word, _)| keystrokes(word).starts_with(&typed))
+            .max_by(|(a, a_count), (b, b_count)| {
+                a_count
+                    .cmp(b_count)
+                    .then_with(|| b.chars().count().cmp(&a.chars().count()))
+                    .then_with(|| b.cmp(a))
+            })
+            .map(|(word, _)| word.as_str())
+    }
+}
+
+/// Spell a text out as the keys that type it: 한 → ㅎ ㅏ ㄴ.
+fn keystrokes(text: &str) -> Vec<char> {
+    text.chars()
+        .flat_map(|c| {
+            let keys = syllable_keystrokes(c);
+            if keys.is_empty() {
+                vec![c]
+            } else {
+                keys
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_complete_by_frequency() {
+        let mut predictor = Predictor::new();
+        predictor.learn("한글 한국 한국, 한국어!");
+        assert_eq!(predictor.vocabulary_size(), 3);
+        assert_eq!(predictor.complete("한"), Some("한국"));
+        assert_eq!(predictor.complete("한구"), Some("한국"));
+        assert_eq!(predictor.complete("한국"), Some("한국어"));
+        assert_eq!(predictor.complete("한국어"), None);
+        assert_eq!(predictor.complete(""), None);
+    }
+
+    #[test]
+    fn test_complete_mid_syllable() {
+        let mut predictor = Predictor::new();
+        predictor.learn("훈민정음");
+        // 후 is on its way to 훈
+        assert_eq!(predictor.complete("후"), Some("훈민정음"));
+        assert_eq!(predictor.complete("훈미"), Some("훈민정음"));
+        assert_eq!(predictor.complete("호"), None);
+    }
+
+    #[test]
+    fn test_ties_prefer_shorter() {
+        let mut predictor = Predictor::new();
+        predictor.learn("아리랑고개 아리랑");
+        assert_eq!(predictor.complete("아"), Some("아리랑"));
+    }
+}
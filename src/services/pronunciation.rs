@@ -0,0 +1,440 @@
+// src/services/pronunciation.rs
+//
+// Standard pronunciation (표준 발음) of committed Hangeul.
+// Spelling keeps morphemes visible; to sing or sonify a line we need what
+// is actually heard. Each word (a run of syllables) is read boundary by
+// boundary, left to right, applying in turn:
+//  - palatalization (구개음화): 굳이 → 구지, 같이 → 가치
+//  - aspiration (격음화): 좋고 → 조코, 축하 → 추카
+//  - liaison (연음): 음악 → 으막, 닭이 → 달기, and a final ㅎ dropped before a vowel
+//  - the seven representative finals: 부엌 → 부억, 옷 → 옫
+//  - nasalization (비음화): 국물 → 궁물, 종로 → 종노, 국립 → 궁닙
+//  - ㄹ-assimilation (유음화): 신라 → 실라, 설날 → 설랄
+//  - tensification (경음화): 학교 → 학꾜
+// Lexical exceptions (밟다, 맛있다 …) are not covered.
+//
+// The pronounced text has exactly as many chars as the input, so positions
+// line up with the spelled line.
+
+use super::jamo::{decompose_conjoining, to_compat};
+use hangeul::compose_char;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum SoundRule {
+    Liaison,
+    Nasalization,
+    Tensification,
+    Aspiration,
+    Palatalization,
+    LateralAssimilation,
+}
+
+/// A rule that fired at the boundary in front of the char at `at`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RuleHit {
+    pub rule: SoundRule,
+    pub at: usize,
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Pronunciation {
+    pub text: String,
+    pub rules: Vec<RuleHit>,
+}
+
+impl Pronunciation {
+    pub fn syllables(&self) -> impl Iterator<Item = char> + '_ {
+        self.text.chars()
+    }
+
+    /// Rules that fired in front of the char at `at`.
+    pub fn rules_at(&self, at: usize) -> impl Iterator<Item = SoundRule> + '_ {
+        self.rules
+            .iter()
+            .filter(move |hit| hit.at == at)
+            .map(|hit| hit.rule)
+    }
+}
+
+// A syllable as compatibility jamo; ㅇ stands for an empty initial.
+#[derive(Debug, Clone, Copy)]
+struct Syllable {
+    original: char, // as typed, before any sound change
+    initial: char,
+    vowel: char,
+    last: Option<char>,
+}
+
+impl Syllable {
+    fn parse(ch: char) -> Option<Self> {
+        let (l, v, t) = decompose_conjoining(ch)?;
+        Some(Self {
+            original: ch,
+            initial: to_compat(l)?,
+            vowel: to_compat(v)?,
+            last: t.and_then(to_compat),
+        })
+    }
+
+    fn compose(&self) -> Option<char> {
+        compose_char(&self.initial, &self.vowel, self.last.as_ref()).ok()
+    }
+}
+
+/// Pronounce a line. Non-Hangeul chars pass through and separate words.
+pub fn pronounce(text: &str) -> Pronunciation {
+    let mut pronunciation = Pronunciation::default();
+    let mut word: Vec<Syllable> = Vec::new();
+    let mut word_start = 0;
+
+    for (i, ch) in text.chars().enumerate() {
+        match Syllable::parse(ch) {
+            Some(syllable) => {
+                if word.is_empty() {
+                    word_start = i;
+                }
+                word.push(syllable);
+            }
+            None => {
+                pronounce_word(&mut word, word_start, &mut pronunciation);
+                pronunciation.text.push(ch);
+            }
+        }
+    }
+    pronounce_word(&mut word, word_start, &mut pronunciation);
+    pronunciation
+}
+
+/// How the last `tail` chars of `text` sound, read as the end of it: what
+/// was just typed, with the words in front of it for context. Rules are
+/// counted from the start of the tail.
+pub fn pronounce_tail(text: &str, tail: usize) -> Pronunciation {
+    let said = pronounce(text);
+    let skip = said.text.chars().count().saturating_sub(tail);
+    Pronunciation {
+        text: said.text.chars().skip(skip).collect(),
+        rules: said
+            .rules
+            .into_iter()
+            .filter(|hit| hit.at >= skip)
+            .map(|hit| RuleHit {
+                rule: hit.rule,
+                at: hit.at - skip,
+            })
+            .collect(),
+    }
+}
+
+fn pronounce_word(word: &mut Vec<Syllable>, start: usize, out: &mut Pronunciation) {
+    for i in 1..word.len() {
+        let (before, after) = word.split_at_mut(i);
+        let rules = apply_boundary(&mut before[i - 1], &mut after[0]);
+        out.rules.extend(rules.into_iter().map(|rule| RuleHit {
+            rule,
+            at: start + i,
+        }));
+    }
+    if let Some(last) = word.last_mut() {
+        last.last = last.last.map(representative_final);
+    }
+
+    for syllable in word.drain(..) {
+        // a syllable that doesn't compose is kept as typed, so the output
+        // still lines up with the input
+        out.text
+            .push(syllable.compose().unwrap_or(syllable.original));
+    }
+}
+
+/// Apply the sound changes between two neighbouring syllables.
+fn apply_boundary(prev: &mut Syllable, next: &mut Syllable) -> Vec<SoundRule> {
+    let mut rules = Vec::new();
+    let Some(last) = prev.last else {
+        return rules;
+    };
+
+    // Palatalization: ㄷ ㅌ before 이 (and ㄷ before 히) become ㅈ ㅊ
+    if next.vowel == 'ㅣ' && matches!(next.initial, 'ㅇ' | 'ㅎ') {
+        let palatal = match (last, next.initial) {
+            ('ㄷ', 'ㅇ') => Some((None, 'ㅈ')),
+            ('ㄷ', 'ㅎ') | ('ㅌ', 'ㅇ') => Some((None, 'ㅊ')),
+            ('ㄾ', 'ㅇ') => Some((Some('ㄹ'), 'ㅊ')),
+            _ => None,
+        };
+        if let Some((kept, initial)) = palatal {
+            prev.last = kept;
+            next.initial = initial;
+            rules.push(SoundRule::Palatalization);
+            return rules;
+        }
+    }
+
+    // Aspiration: a plain stop meets ㅎ
+    if let Some((kept, initial)) = aspirate(last, next.initial) {
+        prev.last = kept;
+        next.initial = initial;
+        rules.push(SoundRule::Aspiration);
+        return rules;
+    }
+
+    // Liaison: the final fills an empty initial
+    if next.initial == 'ㅇ' && last != 'ㅇ' {
+        match split_final(last) {
+            // ㅎ is silent before a vowel: 좋아 → 조아, 많아 → 마나
+            (kept, 'ㅎ') => {
+                prev.last = None;
+                next.initial = kept.unwrap_or('ㅇ');
+            }
+            (kept, moved) => {
+                prev.last = kept;
+                next.initial = moved;
+            }
+        }
+        rules.push(SoundRule::Liaison);
+        return rules;
+    }
+
+    // From here the final is one of the seven sounds (놓는 → 녿는 → 논는)
+    let mut last = representative_final(last);
+    prev.last = Some(last);
+
+    // Nasalization: ㄹ after a nasal or stop becomes ㄴ, then stops before
+    // a nasal become nasals
+    if next.initial == 'ㄹ' && matches!(last, 'ㅁ' | 'ㅇ' | 'ㄱ' | 'ㅂ') {
+        next.initial = 'ㄴ';
+        rules.push(SoundRule::Nasalization);
+    }
+    if matches!(next.initial, 'ㄴ' | 'ㅁ') {
+        let nasal = match last {
+            'ㄱ' => Some('ㅇ'),
+            'ㄷ' => Some('ㄴ'),
+            'ㅂ' => Some('ㅁ'),
+            _ => None,
+        };
+        if let Some(nasal) = nasal {
+            last = nasal;
+            prev.last = Some(nasal);
+            if !rules.contains(&SoundRule::Nasalization) {
+                rules.push(SoundRule::Nasalization);
+            }
+        }
+    }
+
+    // ㄹ-assimilation: ㄴ next to ㄹ becomes ㄹ
+    match (last, next.initial) {
+        ('ㄴ', 'ㄹ') => {
+            prev.last = Some('ㄹ');
+            rules.push(SoundRule::LateralAssimilation);
+        }
+        ('ㄹ', 'ㄴ') => {
+            next.initial = 'ㄹ';
+            rules.push(SoundRule::LateralAssimilation);
+        }
+        _ => {}
+    }
+
+    // Tensification: plain obstruents after a stop final
+    if matches!(last, 'ㄱ' | 'ㄷ' | 'ㅂ') {
+        if let Some(tense) = tense(next.initial) {
+            next.initial = tense;
+            rules.push(SoundRule::Tensification);
+        }
+    }
+    rules
+}
+
+/// Final + initial ㅎ, or final ㅎ + initial stop → (kept final, aspirate).
+fn aspirate(last: char, initial: char) -> Option<(Option<char>, char)> {
+    if initial == 'ㅎ' {
+        let (kept, moved) = match last {
+            'ㄺ' => (Some('ㄹ'), 'ㄱ'),
+            'ㄼ' => (Some('ㄹ'), 'ㅂ'),
+            'ㄵ' => (Some('ㄴ'), 'ㅈ'),
+            'ㄱ' | 'ㄲ' | 'ㅋ' => (None, 'ㄱ'),
+            'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' => (None, 'ㄷ'),
+            'ㅂ' | 'ㅍ' => (None, 'ㅂ'),
+            _ => return None,
+        };
+        return Some((kept, aspirated(moved)?));
+    }
+
+    let kept = match last {
+        'ㅎ' => None,
+        'ㄶ' => Some('ㄴ'),
+        'ㅀ' => Some('ㄹ'),
+        _ => return None,
+    };
+    Some((kept, aspirated(initial)?))
+}
+
+fn aspirated(plain: char) -> Option<char> {
+    match plain {
+        'ㄱ' => Some('ㅋ'),
+        'ㄷ' => Some('ㅌ'),
+        'ㅂ' => Some('ㅍ'),
+        'ㅈ' => Some('ㅊ'),
+        _ => None,
+    }
+}
+
+fn tense(plain: char) -> Option<char> {
+    match plain {
+        'ㄱ' => Some('ㄲ'),
+        'ㄷ' => Some('ㄸ'),
+        'ㅂ' => Some('ㅃ'),
+        'ㅅ' => Some('ㅆ'),
+        'ㅈ' => Some('ㅉ'),
+        _ => None,
+    }
+}
+
+/// What carries over in liaison: a compound final keeps its first half,
+/// a simple one moves whole. ㅅ moved out of a compound is heard tense.
+fn split_final(last: char) -> (Option<char>, char) {
+    match last {
+        'ㄳ' => (Some('ㄱ'), 'ㅆ'),
+        'ㄵ' => (Some('ㄴ'), 'ㅈ'),
+        'ㄶ' => (Some('ㄴ'), 'ㅎ'),
+        'ㄺ' => (Some('ㄹ'), 'ㄱ'),
+        'ㄻ' => (Some('ㄹ'), 'ㅁ'),
+        'ㄼ' => (Some('ㄹ'), 'ㅂ'),
+        'ㄽ' => (Some('ㄹ'), 'ㅆ'),
+        'ㄾ' => (Some('ㄹ'), 'ㅌ'),
+        'ㄿ' => (Some('ㄹ'), 'ㅍ'),
+        'ㅀ' => (Some('ㄹ'), 'ㅎ'),
+        'ㅄ' => (Some('ㅂ'), 'ㅆ'),
+        _ => (None, last),
+    }
+}
+
+/// A syllable can only end in ㄱ ㄴ ㄷ ㄹ ㅁ ㅂ ㅇ.
+pub fn representative_final(last: char) -> char {
+    match last {
+        'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' => 'ㄱ',
+        'ㄴ' | 'ㄵ' | 'ㄶ' => 'ㄴ',
+        'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => 'ㄷ',
+        'ㄹ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => 'ㄹ',
+        'ㅁ' | 'ㄻ' => 'ㅁ',
+        'ㅂ' | 'ㅍ' | 'ㅄ' | 'ㄿ' => 'ㅂ',
+        other => other,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn said(text: &str) -> String {
+        pronounce(text).text
+    }
+
+    #[test]
+    fn test_liaison() {
+        assert_eq!(said("음악"), "으막");
+        assert_eq!(said("닭이"), "달기");
+        assert_eq!(said("있어"), "이써");
+        assert_eq!(said("좋아"), "조아");
+        assert_eq!(said("많아"), "마나");
+    }
+
+    #[test]
+    fn test_nasalization() {
+        assert_eq!(said("국물"), "궁물");
+        assert_eq!(said("종로"), "종노");
+        assert_eq!(said("국립"), "궁닙");
+        assert_eq!(said("놓는"), "논는");
+    }
+
+    #[test]
+    fn test_lateral_assimilation() {
+        assert_eq!(said("신라"), "실라");
+        assert_eq!(said("설날"), "설랄");
+    }
+
+    #[test]
+    fn test_tensification_and_aspiration() {
+        assert_eq!(said("학교"), "학꾜");
+        assert_eq!(said("닭도"), "닥또");
+        assert_eq!(said("좋고"), "조코");
+        assert_eq!(said("축하"), "추카");
+    }
+
+    #[test]
+    fn test_palatalization() {
+        assert_eq!(said("굳이"), "구지");
+        assert_eq!(said("같이"), "가치");
+        assert_eq!(said("닫히다"), "다치다");
+    }
+
+    #[test]
+    fn test_final_neutralization_and_words() {
+        assert_eq!(said("부엌"), "부억");
+        // words don't link across spaces
+        assert_eq!(said("옷 안"), "옫 안");
+    }
+
+    #[test]
+    fn test_rule_positions() {
+        let pronunciation = pronounce("한국말");
+        assert_eq!(pronunciation.text, "한궁말");
+        assert_eq!(
+            pronunciation.rules,
+            vec![RuleHit {
+                rule: SoundRule::Nasalization,
+                at: 2
+            }]
+        );
+        assert_eq!(
+            pronunciation.rules_at(2).collect::<Vec<_>>(),
+            vec![SoundRule::Nasalization]
+        );
+    }
+
+    #[test]
+    fn test_tail() {
+        // the boundary in front of the tail counts, the ones before it don't
+        let tail = pronounce_tail("한국 종로", 1);
+        assert_eq!(tail.text, "노");
+        assert_eq!(
+            tail.rules,
+            vec![RuleHit {
+                rule: SoundRule::Nasalization,
+                at: 0
+            }]
+        );
+        assert_eq!(pronounce_tail("닭이", 1).text, "기");
+        // at the end of what's typed, a final is said as it stands
+        assert_eq!(pronounce_tail("부엌", 1).text, "억");
+        assert_eq!(pronounce_tail("가", 3).text, "가");
+    }
+
+    #[test]
+    fn test_one_syllable_each() {
+        for line in [
+            "닭 앞에 값없이 읽는",
+            "굳이 같이 맑고 넓은 밟다",
+            "ㄱ가, 힣!",
+        ] {
+            assert_eq!(
+                pronounce(line).text.chars().count(),
+                line.chars().count(),
+                "{}",
+                line
+            );
+        }
+
+        // a syllable the rules left malformed falls back to its spelling
+        let mut word = vec![
+            Syllable::parse('국').unwrap(),
+            Syllable {
+                original: '물',
+                initial: 'ㅁ',
+                vowel: 'ㅁ',
+                last: None,
+            },
+        ];
+        let mut out = Pronunciation::default();
+        pronounce_word(&mut word, 0, &mut out);
+        assert_eq!(out.text, "궁물");
+    }
+}
@@ -0,0 +1,189 @@
+// src/services/romaja.rs
+//
+// Revised Romanization → Hangeul transliteration for the romaja input mode.
+//
+// Latin text is split into vowel and consonant units, turned into a stream
+// of compatibility jamo and typed through a HangeulComposer, which already
+// knows how to find syllable boundaries. Romanization is ambiguous in places
+// (jeongeum 정음 vs 전금, gaeul 가을 vs 개울); an apostrophe or hyphen inside
+// a word marks a syllable boundary explicitly: han'geul, ga-eul.
+
+use super::hangeul_composer::HangeulComposer;
+
+// Longest spellings first so greedy matching picks "yeo" before "ye".
+const VOWELS: [(&str, char); 21] = [
+    ("yeo", 'ㅕ'),
+    ("yae", 'ㅒ'),
+    ("wae", 'ㅙ'),
+    ("ya", 'ㅑ'),
+    ("yo", 'ㅛ'),
+    ("yu", 'ㅠ'),
+    ("ye", 'ㅖ'),
+    ("wa", 'ㅘ'),
+    ("wo", 'ㅝ'),
+    ("we", 'ㅞ'),
+    ("wi", 'ㅟ'),
+    ("ui", 'ㅢ'),
+    ("eo", 'ㅓ'),
+    ("eu", 'ㅡ'),
+    ("ae", 'ㅐ'),
+    ("oe", 'ㅚ'),
+    ("a", 'ㅏ'),
+    ("e", 'ㅔ'),
+    ("i", 'ㅣ'),
+    ("o", 'ㅗ'),
+    ("u", 'ㅜ'),
+];
+
+// (spelling, as initial, as final). k/t/p are written for both ㅋㅌㅍ
+// initials and ㄱㄷㅂ finals; ng only ever closes a syllable. "ng" before a
+// vowel could also be ㄴ+ㄱ: we read it as ㅇ (jeongeum 정음) and leave the
+// other reading to a boundary mark (han'geul 한글).
+const CONSONANTS: [(&str, Option<char>, char); 20] = [
+    ("kk", Some('ㄲ'), 'ㄲ'),
+    ("tt", Some('ㄸ'), 'ㄷ'),
+    ("pp", Some('ㅃ'), 'ㅂ'),
+    ("ss", Some('ㅆ'), 'ㅆ'),
+    ("jj", Some('ㅉ'), 'ㅈ'),
+    ("ch", Some('ㅊ'), 'ㅊ'),
+    ("ng", None, 'ㅇ'),
+    ("g", Some('ㄱ'), 'ㄱ'),
+    ("k", Some('ㅋ'), 'ㄱ'),
+    ("n", Some('ㄴ'), 'ㄴ'),
+    ("d", Some('ㄷ'), 'ㄷ'),
+    ("t", Some('ㅌ'), 'ㄷ'),
+    ("r", Some('ㄹ'), 'ㄹ'),
+    ("l", Some('ㄹ'), 'ㄹ'),
+    ("m", Some('ㅁ'), 'ㅁ'),
+    ("b", Some('ㅂ'), 'ㅂ'),
+    ("p", Some('ㅍ'), 'ㅂ'),
+    ("s", Some('ㅅ'), 'ㅅ'),
+    ("j", Some('ㅈ'), 'ㅈ'),
+    ("h", Some('ㅎ'), 'ㅎ'),
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Unit {
+    Vowel(char),
+    Consonant { initial: Option<char>, last: char },
+    Boundary,
+    Other(char),
+}
+
+/// Characters that mark a syllable boundary inside a romanized word.
+pub fn is_boundary_mark(ch: char) -> bool {
+    matches!(ch, '\'' | '-')
+}
+
+/// Transliterate romanized Latin text into Hangeul.
+pub fn romaja_to_hangeul(latin: &str) -> String {
+    let mut composer = HangeulComposer::new();
+    for jamo in romaja_to_jamo(latin) {
+        composer.push_char(jamo);
+    }
+    composer.commit();
+    composer.committed()
+}
+
+/// Transliterate romanized Latin text into the compatibility jamo a
+/// dubeolsik typist would press for it.
+pub fn romaja_to_jamo(latin: &str) -> Vec<char> {
+    let units = split_units(&latin.to_lowercase());
+    let mut jamo = Vec::new();
+
+    // Whether the last thing emitted can serve as the initial of a vowel
+    let mut has_initial = false;
+
+    for (i, unit) in units.iter().enumerate() {
+        match *unit {
+            Unit::Vowel(v) => {
+                if !has_initial {
+                    // silent ㅇ in front of a vowel-initial syllable
+                    jamo.push('ㅇ');
+                }
+                jamo.push(v);
+                has_initial = false;
+            }
+            Unit::Consonant { initial, last } => {
+                let before_vowel = matches!(units.get(i + 1), Some(Unit::Vowel(_)));
+                match initial {
+                    Some(initial) if before_vowel => {
+                        jamo.push(initial);
+                        has_initial = true;
+                    }
+                    _ => {
+                        jamo.push(last);
+                        has_initial = false;
+                    }
+                }
+            }
+            // A consonant before a boundary was already emitted as a final,
+            // so the next vowel gets its own ㅇ
+            Unit::Boundary => {}
+            Unit::Other(ch) => {
+                jamo.push(ch);
+                has_initial = false;
+            }
+        }
+    }
+    jamo
+}
+
+fn split_units(latin: &str) -> Vec<Unit> {
+    let mut units = Vec::new();
+    let mut rest = latin;
+
+    'outer: while let Some(ch) = rest.chars().next() {
+        if is_boundary_mark(ch) {
+            units.push(Unit::Boundary);
+            rest = &rest[ch.len_utf8()..];
+            continue;
+        }
+        for (spelling, vowel) in VOWELS {
+            if let Some(tail) = rest.strip_prefix(spelling) {
+                units.push(Unit::Vowel(vowel));
+                rest = tail;
+                continue 'outer;
+            }
+        }
+        for (spelling, initial, last) in CONSONANTS {
+            if let Some(tail) = rest.strip_prefix(spelling) {
+                units.push(Unit::Consonant { initial, last });
+                rest = tail;
+                continue 'outer;
+            }
+        }
+        units.push(Unit::Other(ch));
+        rest = &rest[ch.len_utf8()..];
+    }
+    units
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_words() {
+        assert_eq!(romaja_to_hangeul("hunminjeongeum"), "훈민정음");
+        assert_eq!(romaja_to_hangeul("sarang"), "사랑");
+        assert_eq!(romaja_to_hangeul("hakgyo"), "학교");
+        assert_eq!(romaja_to_hangeul("seoul"), "서울");
+        assert_eq!(romaja_to_hangeul("gimchi"), "김치");
+        assert_eq!(romaja_to_hangeul("bap"), "밥");
+    }
+
+    #[test]
+    fn test_vowel_initial_and_compounds() {
+        assert_eq!(romaja_to_hangeul("ai"), "아이");
+        assert_eq!(romaja_to_hangeul("gwanghwamun"), "광화문");
+        assert_eq!(romaja_to_hangeul("uisa"), "의사");
+    }
+
+    #[test]
+    fn test_boundary_marks() {
+        assert_eq!(romaja_to_hangeul("hangeul"), "항을");
+        assert_eq!(romaja_to_hangeul("han'geul"), "한글");
+        assert_eq!(romaja_to_hangeul("ga-eul"), "가을");
+    }
+}
@@ -0,0 +1,294 @@
+// src/services/romanization.rs
+//
+// Hangeul → Latin for the subtitle line under the main output.
+// Two systems: Revised Romanization (the South Korean standard) and
+// McCune–Reischauer. Both are pronunciation based, so every syllable is
+// read against its neighbours: a final consonant carries over to a
+// following vowel (음악 → eumak), ㄹㄹ is "ll", and in MR plain stops are
+// voiced between voiced sounds (부부 → pubu). Sound changes across the
+// syllable boundary beyond that are not applied.
+//
+// Anything that isn't a Hangeul syllable passes through and breaks the word.
+
+use super::jamo::{decompose_conjoining, to_compat};
+use serde::Deserialize;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RomanizationSystem {
+    #[default]
+    Rr,
+    Mr,
+}
+
+// Syllable as compatibility jamo; `initial` is None for the silent ㅇ.
+#[derive(Debug, Clone, Copy)]
+struct Syllable {
+    initial: Option<char>,
+    vowel: char,
+    last: Option<char>,
+}
+
+fn parse(ch: char) -> Option<Syllable> {
+    let (l, v, t) = decompose_conjoining(ch)?;
+    let initial = to_compat(l).filter(|c| *c != 'ㅇ');
+    Some(Syllable {
+        initial,
+        vowel: to_compat(v)?,
+        last: t.and_then(to_compat),
+    })
+}
+
+/// Romanize a whole text, one word at a time.
+pub fn romanize(text: &str, system: RomanizationSystem) -> String {
+    let mut out = String::new();
+    let mut word = Vec::new();
+
+    for ch in text.chars() {
+        match parse(ch) {
+            Some(syllable) => word.push(syllable),
+            None => {
+                romanize_word(&word, system, &mut out);
+                word.clear();
+                out.push(ch);
+            }
+        }
+    }
+    romanize_word(&word, system, &mut out);
+    out
+}
+
+fn romanize_word(word: &[Syllable], system: RomanizationSystem, out: &mut String) {
+    // Liaison: a final moves over to a following silent ㅇ (ㅇ and ㅎ don't)
+    let mut word = word.to_vec();
+    for i in 1..word.len() {
+        if word[i].initial.is_some() {
+            continue;
+        }
+        let Some(last) = word[i - 1].last else {
+            continue;
+        };
+        let (kept, moved) = split_final(last);
+        if moved == 'ㅇ' {
+            continue;
+        }
+        word[i - 1].last = kept;
+        word[i].initial = (moved != 'ㅎ').then_some(moved);
+    }
+
+    let mut prev_final: Option<char> = None;
+    for (i, syllable) in word.iter().enumerate() {
+        let word_start = i == 0;
+        // after a vowel or a voiced final
+        let after_voiced = !word_start
+            && match prev_final {
+                Some(last) => is_voiced_final(last),
+                None => true,
+            };
+
+        if let Some(initial) = syllable.initial {
+            let latin = match system {
+                RomanizationSystem::Rr => rr_initial(initial, prev_final),
+                RomanizationSystem::Mr => {
+                    mr_initial(initial, prev_final, after_voiced, syllable.vowel)
+                }
+            };
+            out.push_str(latin);
+        }
+        out.push_str(match system {
+            RomanizationSystem::Rr => rr_vowel(syllable.vowel),
+            RomanizationSystem::Mr => mr_vowel(syllable.vowel),
+        });
+        if let Some(last) = syllable.last {
+            out.push_str(final_sound(last));
+        }
+        prev_final = syllable.last;
+    }
+}
+
+/// A compound final splits into (kept, moved); a simple one moves whole.
+/// The ㅅ of ㄳ ㄽ ㅄ moves as ㅅ: 넋이 is 넉씨, and RR doesn't write the
+/// tensing.
+fn split_final(last: char) -> (Option<char>, char) {
+    match last {
+        'ㄳ' => (Some('ㄱ'), 'ㅅ'),
+        'ㄵ' => (Some('ㄴ'), 'ㅈ'),
+        'ㄶ' => (Some('ㄴ'), 'ㅎ'),
+        'ㄺ' => (Some('ㄹ'), 'ㄱ'),
+        'ㄻ' => (Some('ㄹ'), 'ㅁ'),
+        'ㄼ' => (Some('ㄹ'), 'ㅂ'),
+        'ㄽ' => (Some('ㄹ'), 'ㅅ'),
+        'ㄾ' => (Some('ㄹ'), 'ㅌ'),
+        'ㄿ' => (Some('ㄹ'), 'ㅍ'),
+        'ㅀ' => (Some('ㄹ'), 'ㅎ'),
+        'ㅄ' => (Some('ㅂ'), 'ㅅ'),
+        _ => (None, last),
+    }
+}
+
+fn is_voiced_final(last: char) -> bool {
+    matches!(final_sound(last), "n" | "l" | "m" | "ng")
+}
+
+/// The seven sounds a syllable can end in; the same in both systems.
+fn final_sound(last: char) -> &'static str {
+    match last {
+        'ㄱ' | 'ㄲ' | 'ㅋ' | 'ㄳ' | 'ㄺ' => "k",
+        'ㄴ' | 'ㄵ' | 'ㄶ' => "n",
+        'ㄷ' | 'ㅅ' | 'ㅆ' | 'ㅈ' | 'ㅊ' | 'ㅌ' | 'ㅎ' => "t",
+        'ㄹ' | 'ㄼ' | 'ㄽ' | 'ㄾ' | 'ㅀ' => "l",
+        'ㅁ' | 'ㄻ' => "m",
+        'ㅂ' | 'ㅍ' | 'ㅄ' | 'ㄿ' => "p",
+        'ㅇ' => "ng",
+        _ => "",
+    }
+}
+
+fn rr_initial(initial: char, prev_final: Option<char>) -> &'static str {
+    match initial {
+        'ㄱ' => "g",
+        'ㄲ' => "kk",
+        'ㄴ' => "n",
+        'ㄷ' => "d",
+        'ㄸ' => "tt",
+        'ㄹ' if prev_final.is_some_and(|t| final_sound(t) == "l") => "l",
+        'ㄹ' => "r",
+        'ㅁ' => "m",
+        'ㅂ' => "b",
+        'ㅃ' => "pp",
+        'ㅅ' => "s",
+        'ㅆ' => "ss",
+        'ㅈ' => "j",
+        'ㅉ' => "jj",
+        'ㅊ' => "ch",
+        'ㅋ' => "k",
+        'ㅌ' => "t",
+        'ㅍ' => "p",
+        'ㅎ' => "h",
+        _ => "",
+    }
+}
+
+fn mr_initial(
+    initial: char,
+    prev_final: Option<char>,
+    after_voiced: bool,
+    vowel: char,
+) -> &'static str {
+    match initial {
+        // n'g keeps 한글 apart from 항글
+        'ㄱ' if prev_final.is_some_and(|t| final_sound(t) == "n") => "'g",
+        'ㄱ' if after_voiced => "g",
+        'ㄱ' => "k",
+        'ㄲ' => "kk",
+        'ㄴ' => "n",
+        'ㄷ' if after_voiced => "d",
+        'ㄷ' => "t",
+        'ㄸ' => "tt",
+        'ㄹ' if prev_final.is_some_and(|t| final_sound(t) == "l") => "l",
+        'ㄹ' => "r",
+        'ㅁ' => "m",
+        'ㅂ' if after_voiced => "b",
+        'ㅂ' => "p",
+        'ㅃ' => "pp",
+        'ㅅ' if matches!(vowel, 'ㅣ' | 'ㅟ') => "sh",
+        'ㅅ' => "s",
+        'ㅆ' => "ss",
+        'ㅈ' if after_voiced => "j",
+        'ㅈ' => "ch",
+        'ㅉ' => "tch",
+        'ㅊ' => "ch'",
+        'ㅋ' => "k'",
+        'ㅌ' => "t'",
+        'ㅍ' => "p'",
+        'ㅎ' => "h",
+        _ => "",
+    }
+}
+
+fn rr_vowel(vowel: char) -> &'static str {
+    match vowel {
+        'ㅏ' => "a",
+        'ㅐ' => "ae",
+        'ㅑ' => "ya",
+        'ㅒ' => "yae",
+        'ㅓ' => "eo",
+        'ㅔ' => "e",
+        'ㅕ' => "yeo",
+        'ㅖ' => "ye",
+        'ㅗ' => "o",
+        'ㅘ' => "wa",
+        'ㅙ' => "wae",
+        'ㅚ' => "oe",
+        'ㅛ' => "yo",
+        'ㅜ' => "u",
+        'ㅝ' => "wo",
+        'ㅞ' => "we",
+        'ㅟ' => "wi",
+        'ㅠ' => "yu",
+        'ㅡ' => "eu",
+        'ㅢ' => "ui",
+        'ㅣ' => "i",
+        _ => "",
+    }
+}
+
+fn mr_vowel(vowel: char) -> &'static str {
+    match vowel {
+        'ㅓ' => "ŏ",
+        'ㅕ' => "yŏ",
+        'ㅝ' => "wŏ",
+        'ㅡ' => "ŭ",
+        'ㅢ' => "ŭi",
+        _ => rr_vowel(vowel),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rr(text: &str) -> String {
+        romanize(text, RomanizationSystem::Rr)
+    }
+
+    fn mr(text: &str) -> String {
+        romanize(text, RomanizationSystem::Mr)
+    }
+
+    #[test]
+    fn test_revised_romanization() {
+        assert_eq!(rr("한글"), "hangeul");
+        assert_eq!(rr("훈민정음"), "hunminjeongeum");
+        assert_eq!(rr("부산"), "busan");
+        assert_eq!(rr("음악"), "eumak");
+        assert_eq!(rr("울릉도"), "ulleungdo");
+        assert_eq!(rr("밖"), "bak");
+    }
+
+    #[test]
+    fn test_compound_finals_before_vowels() {
+        assert_eq!(rr("넋이"), "neoksi");
+        assert_eq!(rr("값이"), "gapsi");
+        assert_eq!(rr("곬이"), "golsi");
+        assert_eq!(rr("닭이"), "dalgi");
+        assert_eq!(rr("앉아"), "anja");
+        assert_eq!(rr("젊은"), "jeolmeun");
+        assert_eq!(mr("값이"), "kapshi");
+    }
+
+    #[test]
+    fn test_mccune_reischauer() {
+        assert_eq!(mr("한글"), "han'gŭl");
+        assert_eq!(mr("항구"), "hanggu");
+        assert_eq!(mr("부부"), "pubu");
+        assert_eq!(mr("시조"), "shijo");
+        assert_eq!(mr("판소리"), "p'ansori");
+    }
+
+    #[test]
+    fn test_passthrough() {
+        assert_eq!(rr("한글, OK"), "hangeul, OK");
+        assert_eq!(rr("ㄱ"), "ㄱ");
+    }
+}
@@ -0,0 +1,144 @@
+// src/services/sijo.rs
+//
+// Sijo (시조) form: three lines (초장, 중장, 종장) of four groups each,
+// roughly 3-4-3-4 / 3-4-3-4 / 3-5-4-3 syllables. Every line of submitted
+// text is one line of the poem, whether it came in alone or as part of a
+// stanza; groups are separated by spaces.
+//
+// The meter is loose in practice, so each group allows a range. The last
+// line is the strict one: it always opens with three syllables, and its
+// second group runs long.
+
+use super::jamo::is_syllable;
+
+// Allowed syllables (min, max) per group, by line.
+pub const METER: [[(usize, usize); 4]; 3] = [
+    [(2, 4), (3, 5), (2, 4), (3, 5)],
+    [(2, 4), (3, 5), (2, 4), (3, 5)],
+    [(3, 3), (5, 8), (4, 5), (3, 4)],
+];
+
+pub const LINES: usize = 3;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GroupCheck {
+    pub text: String,
+    pub syllables: usize,
+    pub expected: Option<(usize, usize)>, // None past the fourth group
+    pub fits: bool,
+}
+
+/// Count and check each group of the line at `line_index` (0-based).
+pub fn check_line(line_index: usize, line: &str) -> Vec<GroupCheck> {
+    let meter = METER.get(line_index);
+
+    line.split_whitespace()
+        .enumerate()
+        .map(|(group, text)| {
+            let syllables = count_syllables(text);
+            let expected = meter.and_then(|m| m.get(group)).copied();
+            let fits = expected.is_some_and(|(min, max)| (min..=max).contains(&syllables));
+            GroupCheck {
+                text: text.to_owned(),
+                syllables,
+                expected,
+                fits,
+            }
+        })
+        .collect()
+}
+
+/// Whether the line has four groups that all keep the meter.
+pub fn line_fits(line_index: usize, line: &str) -> bool {
+    let groups = check_line(line_index, line);
+    groups.len() == 4 && groups.iter().all(|group| group.fits)
+}
+
+/// Hangeul syllables in `text`: precomposed ones, plus one per choseong
+/// for syllables spelled out in conjoining jamo (Old Hangeul).
+pub fn count_syllables(text: &str) -> usize {
+    text.chars()
+        .filter(|&c| {
+            is_syllable(c)
+                || ('\u{1100}'..='\u{115F}').contains(&c)
+                || ('\u{A960}'..='\u{A97C}').contains(&c)
+        })
+        .count()
+}
+
+/// The lines of the poem in progress: the finished lines that belong to
+/// it followed by the line being typed. Submitted stanzas and the text
+/// being typed are split at their line breaks. A finished poem stays up
+/// until the first line of the next one is started.
+pub fn poem_lines<'a>(submitted: &'a [String], typing: &'a str) -> Vec<&'a str> {
+    let mut finished: Vec<&str> = submitted.iter().flat_map(|s| s.split('\n')).collect();
+    let mut typing_lines: Vec<&str> = typing.split('\n').collect();
+    let typing = typing_lines.pop().unwrap_or_default();
+    finished.extend(typing_lines);
+
+    let done = finished.len() % LINES;
+    if done == 0 && typing.is_empty() && !finished.is_empty() {
+        return finished[finished.len() - LINES..].to_vec();
+    }
+
+    let mut lines = finished[finished.len() - done..].to_vec();
+    lines.push(typing);
+    lines
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 이방원, 하여가
+    const HAYEOGA: [&str; 3] = [
+        "이런들 어떠하리 저런들 어떠하리",
+        "만수산 드렁칡이 얽어진들 어떠하리",
+        "우리도 이같이얽어져 백년까지 누리리라",
+    ];
+
+    #[test]
+    fn test_classic_sijo_fits() {
+        for (i, line) in HAYEOGA.iter().enumerate() {
+            assert!(line_fits(i, line), "line {}: {:?}", i, check_line(i, line));
+        }
+    }
+
+    #[test]
+    fn test_meter_breaks() {
+        let groups = check_line(0, "이런들 어 저런들 어떠하리 또");
+        assert!(!groups[1].fits);
+        assert_eq!(groups[1].syllables, 1);
+        assert!(groups[2].fits);
+        // a fifth group never fits
+        assert_eq!(groups[4].expected, None);
+        assert!(!groups[4].fits);
+
+        // the last line must open with exactly three syllables
+        assert!(check_line(2, "우리도 이같이얽어져")[0].fits);
+        assert!(!check_line(2, "우리 이같이얽어져")[0].fits);
+        assert!(!check_line(2, "우리들도 이같이얽어져")[0].fits);
+        // and its second group runs long
+        assert!(!check_line(2, "우리도 이같이")[1].fits);
+    }
+
+    #[test]
+    fn test_poem_lines() {
+        let history: Vec<String> = HAYEOGA.iter().map(|l| l.to_string()).collect();
+        assert_eq!(poem_lines(&[], "이런들"), vec!["이런들"]);
+        assert_eq!(poem_lines(&history[..1], ""), vec![HAYEOGA[0], ""]);
+        // finished poem stays up
+        assert_eq!(poem_lines(&history, ""), HAYEOGA.to_vec());
+        // until the next one starts
+        assert_eq!(poem_lines(&history, "새"), vec!["새"]);
+
+        // a stanza counts line by line, submitted or still being typed
+        let stanza = vec![HAYEOGA[..2].join("\n")];
+        assert_eq!(
+            poem_lines(&stanza, "우리도"),
+            vec![HAYEOGA[0], HAYEOGA[1], "우리도"]
+        );
+        let typing = HAYEOGA.join("\n");
+        assert_eq!(poem_lines(&[], &typing), HAYEOGA.to_vec());
+    }
+}
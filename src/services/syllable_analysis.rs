@@ -0,0 +1,284 @@
+// src/services/syllable_analysis.rs
+//
+// Shape and sound of each committed syllable, as data for visuals and music.
+// Every precomposed syllable gets the same features every time, so a typed
+// line always drives the same picture and the same phrase.
+//
+// The classes follow the 훈민정음 해례:
+//  - initials by where they are made (아·설·순·치·후음) and by how strongly
+//    (불청불탁 → 전청 → 차청 → 전탁, i.e. sonorant, plain, aspirated, tense)
+//  - vowels as 양 (ㆍㅗㅏㅛㅑ, bright), 음 (ㅡㅜㅓㅠㅕ, dark) or neutral (ㅣ);
+//    a compound takes the side of its first stroke that isn't ㅣ
+//  - finals by the sound they are left with (종성부용초성, the seven
+//    representative finals)
+// Old Hangeul spelled out in conjoining jamo is not analysed.
+
+use super::hangeul_composer::{split_compound_vowel, split_final_jamo, syllable_keystrokes};
+use super::jamo::{decompose_conjoining, to_compat};
+use super::pronunciation::representative_final;
+
+/// Length of `SyllableFeatures::vector`.
+pub const FEATURE_LEN: usize = 8;
+
+/// Most strokes a syllable can have (쀒: ㅃ 8 + ㅞ 5 + ㄿ 7), for scaling.
+pub const MAX_STROKES: u32 = 20;
+
+/// Where the vowel sits against the initial.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum VowelAxis {
+    Vertical,   // to the right: ㅏ ㅓ ㅣ …
+    Horizontal, // underneath: ㅗ ㅜ ㅡ …
+    Mixed,      // wrapping round: ㅘ ㅝ ㅢ …
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum FinalKind {
+    Open,
+    Single,
+    Compound, // two letters underneath: ㄳ ㄺ … and the doubled ㄲ ㅆ
+}
+
+/// Place of articulation (오음).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OnsetClass {
+    Velar,   // 아음 ㄱ ㅋ ㄲ
+    Lingual, // 설음 ㄴ ㄷ ㅌ ㄸ, and the half-lingual ㄹ
+    Labial,  // 순음 ㅁ ㅂ ㅍ ㅃ
+    Dental,  // 치음 ㅅ ㅈ ㅊ ㅆ ㅉ
+    Glottal, // 후음 ㅇ ㅎ
+}
+
+/// Manner of the initial (청탁).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Manner {
+    Sonorant,  // 불청불탁 ㄴ ㄹ ㅁ ㅇ
+    Plain,     // 전청 ㄱ ㄷ ㅂ ㅅ ㅈ
+    Aspirated, // 차청 ㅋ ㅌ ㅍ ㅊ ㅎ
+    Tense,     // 전탁 ㄲ ㄸ ㅃ ㅆ ㅉ
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Harmony {
+    Yang,
+    Yin,
+    Neutral,
+}
+
+impl Harmony {
+    /// Bright 1.0, dark -1.0, neutral 0.0.
+    pub fn brightness(self) -> f32 {
+        match self {
+            Harmony::Yang => 1.0,
+            Harmony::Yin => -1.0,
+            Harmony::Neutral => 0.0,
+        }
+    }
+}
+
+/// What the final leaves ringing.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CodaClass {
+    Open,
+    Nasal,  // ㄴ ㅁ ㅇ
+    Liquid, // ㄹ
+    Stop,   // ㄱ ㄷ ㅂ, which every other final falls to
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SyllableFeatures {
+    pub syllable: char,
+    pub axis: VowelAxis,
+    pub final_kind: FinalKind,
+    pub strokes: u32,
+    pub onset: OnsetClass,
+    pub manner: Manner,
+    pub harmony: Harmony,
+    pub coda: CodaClass,
+}
+
+impl SyllableFeatures {
+    /// Features of a precomposed syllable; None for anything else.
+    pub fn of(syllable: char) -> Option<Self> {
+        let (l, v, t) = decompose_conjoining(syllable)?;
+        let initial = to_compat(l)?;
+        let vowel = to_compat(v)?;
+        let last = t.and_then(to_compat);
+
+        let strokes = syllable_keystrokes(syllable)
+            .into_iter()
+            .map(letter_strokes)
+            .sum();
+        let final_kind = match last {
+            None => FinalKind::Open,
+            Some('ㄲ' | 'ㅆ') => FinalKind::Compound,
+            Some(last) if split_final_jamo(last).0.is_some() => FinalKind::Compound,
+            Some(_) => FinalKind::Single,
+        };
+
+        Some(Self {
+            syllable,
+            axis: vowel_axis(vowel),
+            final_kind,
+            strokes,
+            onset: onset_class(initial),
+            manner: manner(initial),
+            harmony: harmony(vowel),
+            coda: last.map_or(CodaClass::Open, coda_class),
+        })
+    }
+
+    pub fn has_final(&self) -> bool {
+        self.final_kind != FinalKind::Open
+    }
+
+    /// The features as numbers, each in 0.0..=1.0 except brightness
+    /// (-1.0..=1.0): onset, manner, brightness, axis, final kind, coda,
+    /// strokes, has final.
+    pub fn vector(&self) -> [f32; FEATURE_LEN] {
+        [
+            self.onset as usize as f32 / 4.0,
+            self.manner as usize as f32 / 3.0,
+            self.harmony.brightness(),
+            self.axis as usize as f32 / 2.0,
+            self.final_kind as usize as f32 / 2.0,
+            self.coda as usize as f32 / 3.0,
+            self.strokes as f32 / MAX_STROKES as f32,
+            if self.has_final() { 1.0 } else { 0.0 },
+        ]
+    }
+}
+
+/// Features of every precomposed syllable in `text`, in order.
+pub fn analyze(text: &str) -> Vec<SyllableFeatures> {
+    text.chars().filter_map(SyllableFeatures::of).collect()
+}
+
+fn vowel_axis(vowel: char) -> VowelAxis {
+    match vowel {
+        'ㅗ' | 'ㅛ' | 'ㅜ' | 'ㅠ' | 'ㅡ' => VowelAxis::Horizontal,
+        'ㅘ' | 'ㅙ' | 'ㅚ' | 'ㅝ' | 'ㅞ' | 'ㅟ' | 'ㅢ' => VowelAxis::Mixed,
+        _ => VowelAxis::Vertical,
+    }
+}
+
+fn harmony(vowel: char) -> Harmony {
+    let first = split_compound_vowel(vowel).map_or(vowel, |(first, _)| first);
+    match first {
+        'ㅏ' | 'ㅐ' | 'ㅑ' | 'ㅒ' | 'ㅗ' | 'ㅛ' => Harmony::Yang,
+        'ㅓ' | 'ㅔ' | 'ㅕ' | 'ㅖ' | 'ㅜ' | 'ㅠ' | 'ㅡ' => Harmony::Yin,
+        _ => Harmony::Neutral,
+    }
+}
+
+fn onset_class(initial: char) -> OnsetClass {
+    match initial {
+        'ㄱ' | 'ㅋ' | 'ㄲ' => OnsetClass::Velar,
+        'ㄴ' | 'ㄷ' | 'ㅌ' | 'ㄸ' | 'ㄹ' => OnsetClass::Lingual,
+        'ㅁ' | 'ㅂ' | 'ㅍ' | 'ㅃ' => OnsetClass::Labial,
+        'ㅅ' | 'ㅈ' | 'ㅊ' | 'ㅆ' | 'ㅉ' => OnsetClass::Dental,
+        _ => OnsetClass::Glottal,
+    }
+}
+
+fn manner(initial: char) -> Manner {
+    match initial {
+        'ㄴ' | 'ㄹ' | 'ㅁ' | 'ㅇ' => Manner::Sonorant,
+        'ㅋ' | 'ㅌ' | 'ㅍ' | 'ㅊ' | 'ㅎ' => Manner::Aspirated,
+        'ㄲ' | 'ㄸ' | 'ㅃ' | 'ㅆ' | 'ㅉ' => Manner::Tense,
+        _ => Manner::Plain,
+    }
+}
+
+fn coda_class(last: char) -> CodaClass {
+    match representative_final(last) {
+        'ㄴ' | 'ㅁ' | 'ㅇ' => CodaClass::Nasal,
+        'ㄹ' => CodaClass::Liquid,
+        _ => CodaClass::Stop,
+    }
+}
+
+// Strokes of a single letter, in the usual print forms.
+fn letter_strokes(letter: char) -> u32 {
+    match letter {
+        'ㄱ' | 'ㄴ' | 'ㅇ' | 'ㅡ' | 'ㅣ' => 1,
+        'ㄷ' | 'ㅅ' | 'ㅈ' | 'ㅋ' | 'ㄲ' | 'ㅏ' | 'ㅓ' | 'ㅗ' | 'ㅜ' => 2,
+        'ㄹ' | 'ㅁ' | 'ㅊ' | 'ㅌ' | 'ㅎ' | 'ㅐ' | 'ㅑ' | 'ㅔ' | 'ㅕ' | 'ㅛ' | 'ㅠ' => 3,
+        'ㅂ' | 'ㅍ' | 'ㄸ' | 'ㅆ' | 'ㅉ' | 'ㅒ' | 'ㅖ' => 4,
+        'ㅃ' => 8,
+        _ => 0,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_block_shapes() {
+        let ga = SyllableFeatures::of('가').unwrap();
+        assert_eq!(ga.axis, VowelAxis::Vertical);
+        assert_eq!(ga.final_kind, FinalKind::Open);
+        assert!(!ga.has_final());
+
+        let gong = SyllableFeatures::of('공').unwrap();
+        assert_eq!(gong.axis, VowelAxis::Horizontal);
+        assert_eq!(gong.final_kind, FinalKind::Single);
+
+        let gwalk = SyllableFeatures::of('괅').unwrap();
+        assert_eq!(gwalk.axis, VowelAxis::Mixed);
+        assert_eq!(gwalk.final_kind, FinalKind::Compound);
+        assert_eq!(
+            SyllableFeatures::of('밖').unwrap().final_kind,
+            FinalKind::Compound
+        );
+        assert!(SyllableFeatures::of('a').is_none());
+    }
+
+    #[test]
+    fn test_strokes() {
+        // ㅎ 3 + ㅏ 2 + ㄴ 1
+        assert_eq!(SyllableFeatures::of('한').unwrap().strokes, 6);
+        // ㄱ 1 + ㅡ 1 + ㄹ 3
+        assert_eq!(SyllableFeatures::of('글').unwrap().strokes, 5);
+        // ㅃ 8 + ㅜ 2 + ㅔ 3 + ㄹ 3 + ㅍ 4
+        assert_eq!(SyllableFeatures::of('쀒').unwrap().strokes, MAX_STROKES);
+    }
+
+    #[test]
+    fn test_haerye_classes() {
+        let features = analyze("까 나 호 의");
+        assert_eq!(features.len(), 4);
+        assert_eq!(features[0].onset, OnsetClass::Velar);
+        assert_eq!(features[0].manner, Manner::Tense);
+        assert_eq!(features[1].onset, OnsetClass::Lingual);
+        assert_eq!(features[1].manner, Manner::Sonorant);
+        assert_eq!(features[2].onset, OnsetClass::Glottal);
+        assert_eq!(features[2].manner, Manner::Aspirated);
+
+        assert_eq!(features[0].harmony, Harmony::Yang);
+        assert_eq!(features[2].harmony, Harmony::Yang);
+        assert_eq!(features[3].harmony, Harmony::Yin);
+        assert_eq!(
+            SyllableFeatures::of('이').unwrap().harmony,
+            Harmony::Neutral
+        );
+        assert_eq!(SyllableFeatures::of('왜').unwrap().harmony, Harmony::Yang);
+    }
+
+    #[test]
+    fn test_coda_and_vector() {
+        let coda = |s| SyllableFeatures::of(s).unwrap().coda;
+        assert_eq!(coda('가'), CodaClass::Open);
+        assert_eq!(coda('강'), CodaClass::Nasal);
+        assert_eq!(coda('갈'), CodaClass::Liquid);
+        assert_eq!(coda('갓'), CodaClass::Stop);
+        assert_eq!(coda('값'), CodaClass::Stop);
+        assert_eq!(coda('삶'), CodaClass::Nasal);
+
+        let vector = SyllableFeatures::of('쀒').unwrap().vector();
+        assert_eq!(vector.len(), FEATURE_LEN);
+        assert_eq!(vector[6], 1.0);
+        assert_eq!(vector[7], 1.0);
+        assert!(vector.iter().all(|x| (-1.0..=1.0).contains(x)));
+    }
+}
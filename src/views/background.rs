@@ -33,6 +33,11 @@ impl BackgroundManager {
         }
     }
 
+    /// A flash or fade is running.
+    pub fn is_animating(&self) -> bool {
+        self.flasher.is_active() || self.color_fader.is_active()
+    }
+
     pub fn color_fade(&mut self, target_color: Rgb, duration: f32, current_time: f32) {
         self.color_fader
             .start(self.current_color, target_color, duration, current_time);
@@ -1,5 +1,7 @@
 // src/views/mod.rs
 
 pub mod background;
+pub mod syllable_echoes;
 
 pub use background::BackgroundManager;
+pub use syllable_echoes::SyllableEchoes;
@@ -0,0 +1,100 @@
+// src/views/syllable_echoes.rs
+//
+// Each committed syllable leaves a faint, drifting copy of itself behind
+// the text, placed and coloured by what it is made of:
+//   onset class   column across the screen (아 설 순 치 후)
+//   vowel axis    height: vertical vowels high, horizontal low
+//   strokes       size
+//   harmony       colour: yang warm and bright, yin cool and dim
+//   coda          how long it lingers: nasals ring, stops are cut short
+
+use crate::services::syllable_analysis::{
+    CodaClass, Harmony, OnsetClass, SyllableFeatures, VowelAxis, MAX_STROKES,
+};
+use nannou::prelude::*;
+use nannou::text::Font;
+
+// Most echoes on screen; the oldest go first.
+const MAX_ECHOES: usize = 64;
+// How far an echo rises over its life
+const DRIFT: f32 = 120.0;
+// Strongest an echo gets, so the typed text stays on top
+const MAX_ALPHA: f32 = 0.35;
+
+struct Echo {
+    text: String,
+    position: Point2,
+    font_size: u32,
+    color: (f32, f32, f32),
+    alpha: f32,
+    born: f32,
+    lifetime: f32,
+}
+
+#[derive(Default)]
+pub struct SyllableEchoes {
+    echoes: Vec<Echo>,
+}
+
+impl SyllableEchoes {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, features: &SyllableFeatures, current_time: f32) {
+        let column = match features.onset {
+            OnsetClass::Velar => -2.0,
+            OnsetClass::Lingual => -1.0,
+            OnsetClass::Labial => 0.0,
+            OnsetClass::Dental => 1.0,
+            OnsetClass::Glottal => 2.0,
+        };
+        let height = match features.axis {
+            VowelAxis::Vertical => 250.0,
+            VowelAxis::Mixed => 0.0,
+            VowelAxis::Horizontal => -250.0,
+        };
+        let color = match features.harmony {
+            Harmony::Yang => (1.0, 0.8, 0.55),
+            Harmony::Yin => (0.45, 0.55, 1.0),
+            Harmony::Neutral => (0.71, 0.71, 1.0),
+        };
+        let lifetime = match features.coda {
+            CodaClass::Open => 2.0,
+            CodaClass::Nasal => 3.5,
+            CodaClass::Liquid => 2.5,
+            CodaClass::Stop => 0.8,
+        };
+        let strokes = features.strokes as f32 / MAX_STROKES as f32;
+
+        self.echoes.push(Echo {
+            text: features.syllable.to_string(),
+            position: pt2(column * 700.0, height),
+            font_size: (80.0 + 160.0 * strokes) as u32,
+            color,
+            // yang echoes burn brighter than yin
+            alpha: MAX_ALPHA * (0.7 + 0.3 * features.harmony.brightness()),
+            born: current_time,
+            lifetime,
+        });
+        if self.echoes.len() > MAX_ECHOES {
+            self.echoes.remove(0);
+        }
+    }
+
+    pub fn draw(&mut self, draw: &Draw, font: &Font, current_time: f32) {
+        self.echoes
+            .retain(|echo| current_time - echo.born < echo.lifetime);
+
+        for echo in &self.echoes {
+            let age = (current_time - echo.born) / echo.lifetime;
+            let (r, g, b) = echo.color;
+            draw.text(&echo.text)
+                .width(echo.font_size as f32 * 2.0)
+                .font(font.clone())
+                .x_y(echo.position.x, echo.position.y + DRIFT * age)
+                .color(rgba(r, g, b, echo.alpha * (1.0 - age)))
+                .font_size(echo.font_size);
+        }
+    }
+}